@@ -0,0 +1,18 @@
+use std::{env, fs, io, path::Path};
+
+#[allow(dead_code)]
+#[path = "src/data/archive.rs"]
+mod archive;
+
+fn main() -> io::Result<()> {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_EMBED_ART").is_none() {
+        return Ok(());
+    }
+
+    println!("cargo:rerun-if-changed=colorscripts");
+    let out_dir = env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    let archive = archive::pack(Path::new("colorscripts"))?;
+    fs::write(Path::new(&out_dir).join("colorscripts.art"), archive)
+}
@@ -0,0 +1,69 @@
+use std::{io, time::Duration};
+
+use ratatui::{backend::Backend, crossterm::event::Event, Terminal};
+
+use crate::{
+    event::{AppEvent, EventSource},
+    keybinding::{handle_key, handle_paste, KeyHandleResult},
+    mouse::handle_mouse,
+    state::AppState,
+    ui::ui,
+};
+
+/// longest wait for an event, so timers fire while idle
+const TICK_RATE: Duration = Duration::from_millis(250);
+
+/// draw and handle events until the app exits or `events` runs out
+pub fn run_app<B: Backend, E: EventSource>(
+    terminal: &mut Terminal<B>,
+    app: &mut AppState,
+    events: &mut E,
+) -> io::Result<()> {
+    loop {
+        terminal.draw(|f| ui(f, app))?;
+
+        app.timers.set_now(events.now());
+        let timeout = app.timers.timeout().map_or(TICK_RATE, |t| t.min(TICK_RATE));
+
+        let Some(event) = events.next(timeout)? else {
+            return Ok(());
+        };
+
+        app.timers.set_now(events.now());
+        if dispatch(terminal, app, event)?.is_exit() {
+            return Ok(());
+        }
+
+        for timer in app.timers.expired() {
+            app.on_timer(timer);
+        }
+    }
+}
+
+fn dispatch<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut AppState,
+    event: AppEvent,
+) -> io::Result<KeyHandleResult> {
+    let event = match event {
+        AppEvent::Input(event) => event,
+        AppEvent::Tick => {
+            app.pokemon_list.prefetch_art();
+            return Ok(KeyHandleResult::Continue);
+        }
+    };
+
+    match event {
+        Event::Key(event) => return Ok(handle_key(app, event)),
+        Event::Mouse(event) => handle_mouse(app, event),
+        Event::Paste(text) => handle_paste(app, &text),
+        // paragraphs recompute their height and clamp the scroll when drawn at the new size
+        Event::Resize(_, _) => {
+            terminal.autoresize()?;
+            app.pokemon_list.mouse.dragging = None;
+        }
+        Event::FocusGained | Event::FocusLost => (),
+    }
+
+    Ok(KeyHandleResult::Continue)
+}
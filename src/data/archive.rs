@@ -0,0 +1,198 @@
+// only std and flate2 here, the build script includes this file to pack the embedded archive
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs,
+    io::{self, Read, Write},
+    path::Path,
+};
+
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+
+const MAGIC: &[u8; 8] = b"PKTXART1";
+
+/// bytes of an index entry with an empty name
+const MIN_ENTRY_LEN: usize = 2 + 8 + 4;
+
+/// file name of an archive in a data directory
+pub const ART_ARCHIVE_FILE: &str = "colorscripts.art";
+
+/// one file holding the whole colorscripts directory
+///
+/// `PKTXART1`, the entry count, then the name length, name, offset and length of each entry,
+/// all little endian, followed by the entries each compressed with deflate so any of them can
+/// be read alone. names are paths in the packed directory like `small/shiny/pikachu`
+pub struct ArtArchive {
+    bytes: Cow<'static, [u8]>,
+    /// name to offset and length of the compressed entry
+    entries: HashMap<String, (usize, usize)>,
+}
+
+impl ArtArchive {
+    pub fn from_bytes(bytes: Cow<'static, [u8]>) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "broken art archive");
+
+        let mut cursor = bytes.strip_prefix(MAGIC).ok_or_else(invalid)?;
+        let mut take = |n: usize| -> io::Result<&[u8]> {
+            let (head, rest) = cursor.split_at_checked(n).ok_or_else(invalid)?;
+            cursor = rest;
+            Ok(head)
+        };
+
+        let count = u32::from_le_bytes(take(4)?.try_into().unwrap()) as usize;
+        // the count is not trusted further than the bytes left can hold
+        if count > (bytes.len() - MAGIC.len() - 4) / MIN_ENTRY_LEN {
+            return Err(invalid());
+        }
+        let mut index = Vec::with_capacity(count);
+        for _ in 0..count {
+            let len = u16::from_le_bytes(take(2)?.try_into().unwrap()) as usize;
+            let name = String::from_utf8(take(len)?.to_vec()).map_err(|_| invalid())?;
+            let offset = u64::from_le_bytes(take(8)?.try_into().unwrap()) as usize;
+            let len = u32::from_le_bytes(take(4)?.try_into().unwrap()) as usize;
+            index.push((name, offset, len));
+        }
+
+        // offsets count from the end of the index
+        let start = bytes.len() - cursor.len();
+        let entries = index
+            .into_iter()
+            .map(|(name, offset, len)| {
+                let begin = start.checked_add(offset).ok_or_else(invalid)?;
+                match begin.checked_add(len) {
+                    Some(end) if end <= bytes.len() => Ok((name, (begin, len))),
+                    _ => Err(invalid()),
+                }
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self { bytes, entries })
+    }
+
+    /// read an archive file into memory
+    pub fn open(path: &Path) -> io::Result<Self> {
+        Self::from_bytes(Cow::Owned(fs::read(path)?))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    /// decompressed content of an entry
+    pub fn get(&self, name: &str) -> Option<Vec<u8>> {
+        let (offset, len) = *self.entries.get(name)?;
+        let mut content = vec![];
+        DeflateDecoder::new(self.bytes.get(offset..offset + len)?)
+            .read_to_end(&mut content)
+            .ok()?;
+        Some(content)
+    }
+}
+
+/// pack every file under `dir` into an archive
+pub fn pack(dir: &Path) -> io::Result<Vec<u8>> {
+    let mut files = vec![];
+    collect_files(dir, dir, &mut files)?;
+    files.sort();
+
+    let mut index = vec![];
+    let mut data = vec![];
+    for name in files.iter() {
+        let mut encoder = DeflateEncoder::new(vec![], Compression::best());
+        encoder.write_all(&fs::read(dir.join(name))?)?;
+        let compressed = encoder.finish()?;
+
+        index.push((name, data.len(), compressed.len()));
+        data.extend(compressed);
+    }
+
+    let mut archive = MAGIC.to_vec();
+    archive.extend((index.len() as u32).to_le_bytes());
+    for (name, offset, len) in index {
+        archive.extend((name.len() as u16).to_le_bytes());
+        archive.extend(name.as_bytes());
+        archive.extend((offset as u64).to_le_bytes());
+        archive.extend((len as u32).to_le_bytes());
+    }
+    archive.extend(data);
+
+    Ok(archive)
+}
+
+/// paths of files under `dir` relative to `root`, separated by `/`
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<String>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            let name = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>();
+            files.push(name.join("/"));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn art_archive_round_trip() {
+        let dir = std::env::temp_dir().join(format!("poketex-archive-{}", std::process::id()));
+        let pikachu = fs::read(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("colorscripts/small/regular/pikachu"),
+        )
+        .unwrap();
+        for colors in ["regular", "shiny"] {
+            fs::create_dir_all(dir.join("small").join(colors)).unwrap();
+            fs::write(dir.join("small").join(colors).join("pikachu"), &pikachu).unwrap();
+        }
+
+        let bytes = pack(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(bytes.len() < pikachu.len());
+
+        let archive = ArtArchive::from_bytes(Cow::Owned(bytes.clone())).unwrap();
+        assert_eq!(archive.len(), 2);
+        assert_eq!(archive.get("small/shiny/pikachu"), Some(pikachu));
+        assert_eq!(archive.get("small/regular/raichu"), None);
+
+        // truncated archives are refused
+        assert!(ArtArchive::from_bytes(Cow::Owned(bytes[..20].to_vec())).is_err());
+    }
+
+    #[test]
+    fn broken_index_is_refused() {
+        let header = |count: u32, offset: u64, len: u32| {
+            let mut bytes = MAGIC.to_vec();
+            bytes.extend(count.to_le_bytes());
+            bytes.extend(1u16.to_le_bytes());
+            bytes.extend(b"a");
+            bytes.extend(offset.to_le_bytes());
+            bytes.extend(len.to_le_bytes());
+            bytes.extend(b"data");
+            Cow::Owned(bytes)
+        };
+
+        assert!(ArtArchive::from_bytes(header(1, 0, 4)).is_ok());
+        // more entries than the bytes can hold
+        assert!(ArtArchive::from_bytes(header(u32::MAX, 0, 4)).is_err());
+        // offsets past the end, and ones that overflow
+        assert!(ArtArchive::from_bytes(header(1, 2, 4)).is_err());
+        assert!(ArtArchive::from_bytes(header(1, u64::MAX, 4)).is_err());
+    }
+}
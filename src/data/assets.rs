@@ -0,0 +1,188 @@
+use std::{
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+};
+
+#[cfg(unix)]
+use xdg::BaseDirectories;
+
+use super::{embedded_art, ArtArchive, ART_ARCHIVE_FILE};
+
+/// art directory in an install dir
+pub const ART_DIR: &str = "colorscripts/small";
+
+/// what was found at a searched path
+#[derive(Debug, PartialEq)]
+pub enum Probe {
+    Found,
+    Missing,
+    /// an archive that can not be read
+    Broken(String),
+}
+
+impl Display for Probe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Probe::Found => write!(f, "found"),
+            Probe::Missing => write!(f, "missing"),
+            Probe::Broken(e) => write!(f, "broken ({})", e),
+        }
+    }
+}
+
+/// where the arts are read from, and every path looked at to find them
+#[derive(Default)]
+pub struct Assets {
+    /// art directory, `None` if none of the searched ones exists
+    pub dir: Option<PathBuf>,
+    pub archive: Option<ArtArchive>,
+    /// file of the archive, `None` for the one built into the binary
+    pub archive_path: Option<PathBuf>,
+    pub searched_dirs: Vec<(PathBuf, Probe)>,
+    pub searched_archives: Vec<(PathBuf, Probe)>,
+}
+
+impl Assets {
+    /// art directory given with `--assets`, else the first one in `install_dirs`,
+    /// and the first readable archive in `data_dir` or `install_dirs`, else the embedded one
+    pub fn find(
+        assets: Option<PathBuf>,
+        data_dir: Option<&Path>,
+        install_dirs: &[PathBuf],
+    ) -> Self {
+        let mut found = Self::default();
+
+        let dirs = match assets {
+            Some(dir) => vec![dir],
+            None => install_dirs.iter().map(|dir| dir.join(ART_DIR)).collect(),
+        };
+        for dir in dirs {
+            let probe = if dir.is_dir() {
+                found.dir.get_or_insert_with(|| dir.clone());
+                Probe::Found
+            } else {
+                Probe::Missing
+            };
+            found.searched_dirs.push((dir, probe));
+        }
+
+        let archives = data_dir
+            .into_iter()
+            .chain(install_dirs.iter().map(PathBuf::as_path))
+            .map(|dir| dir.join(ART_ARCHIVE_FILE));
+        for path in archives {
+            let probe = if !path.is_file() {
+                Probe::Missing
+            } else {
+                match ArtArchive::open(&path) {
+                    Ok(archive) => {
+                        if found.archive.is_none() {
+                            found.archive = Some(archive);
+                            found.archive_path = Some(path.clone());
+                        }
+                        Probe::Found
+                    }
+                    Err(e) => Probe::Broken(e.to_string()),
+                }
+            };
+            found.searched_archives.push((path, probe));
+        }
+
+        if found.archive.is_none() {
+            found.archive = embedded_art();
+        }
+
+        found
+    }
+
+    /// the art directory found, or the one in the current dir
+    pub fn art_dir(&self) -> PathBuf {
+        self.dir.clone().unwrap_or_else(|| PathBuf::from(ART_DIR))
+    }
+}
+
+/// directories poketex may be installed in, in the order they are searched
+pub fn get_install_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![];
+
+    // binary execute path
+    if let Some(execute_dir) = std::env::current_exe()
+        .ok()
+        .and_then(|path| path.parent().map(Path::to_path_buf))
+    {
+        dirs.push(execute_dir);
+    }
+
+    // xdg data home
+    #[cfg(unix)]
+    if let Ok(xdg_dir) = BaseDirectories::new() {
+        dirs.push(xdg_dir.get_data_home().join("poketex"));
+    }
+
+    // Windows equivalent
+    #[cfg(windows)]
+    if let Ok(data_dir) = std::env::var("LOCALAPPDATA") {
+        dirs.push(Path::new(&data_dir).join("poketex"));
+    }
+
+    dirs.push(PathBuf::from("/usr/share/poketex"));
+    dirs.push(PathBuf::from("/usr/local/share/poketex"));
+
+    // default current dir
+    if let Ok(current_dir) = std::env::current_dir() {
+        dirs.push(current_dir);
+    }
+
+    dirs
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::data::pack;
+
+    #[test]
+    fn assets_search_reports_every_path() {
+        let root_dir = std::env::temp_dir().join(format!("poketex-assets-{}", std::process::id()));
+        let [empty, broken, packed] = ["empty", "broken", "packed"].map(|d| root_dir.join(d));
+        fs::create_dir_all(&empty).unwrap();
+        fs::create_dir_all(broken.join(ART_DIR)).unwrap();
+        fs::write(broken.join(ART_ARCHIVE_FILE), "not an archive").unwrap();
+        fs::create_dir_all(packed.join(ART_DIR)).unwrap();
+        fs::write(packed.join(ART_ARCHIVE_FILE), pack(&empty).unwrap()).unwrap();
+
+        let install_dirs = [empty.clone(), broken.clone(), packed.clone()];
+        let assets = Assets::find(None, None, &install_dirs);
+        assert_eq!(assets.dir, Some(broken.join(ART_DIR)));
+        assert_eq!(assets.archive_path, Some(packed.join(ART_ARCHIVE_FILE)));
+        let probes = |searched: &[(PathBuf, Probe)]| {
+            searched
+                .iter()
+                .map(|(_, probe)| probe.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(probes(&assets.searched_dirs), ["missing", "found", "found"]);
+        assert_eq!(
+            probes(&assets.searched_archives),
+            ["missing", "broken (broken art archive)", "found"]
+        );
+
+        // the archive of the data dir comes first
+        fs::write(empty.join(ART_ARCHIVE_FILE), pack(&empty).unwrap()).unwrap();
+        let assets = Assets::find(None, Some(&empty), &install_dirs[1..]);
+        assert_eq!(assets.archive_path, Some(empty.join(ART_ARCHIVE_FILE)));
+        assert_eq!(
+            probes(&assets.searched_archives),
+            ["found", "broken (broken art archive)", "found"]
+        );
+
+        // --assets is the only directory looked at
+        let assets = Assets::find(Some(empty.clone()), None, &install_dirs);
+        assert_eq!(assets.dir, Some(empty.clone()));
+        assert_eq!(assets.searched_dirs.len(), 1);
+
+        fs::remove_dir_all(&root_dir).unwrap();
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    path::PathBuf,
+};
+
+use serde_path_to_error::Segment;
+
+pub type DataResult<T> = Result<T, DataError>;
+
+/// which record of a file a problem belongs to
+#[derive(Debug)]
+pub enum RecordId {
+    /// position in an array file, with the species number when it is known
+    Index { index: usize, no: Option<u64> },
+    /// key in a map file, e.g. ability id
+    Key(String),
+}
+
+impl Display for RecordId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RecordId::Index {
+                index,
+                no: Some(no),
+            } => write!(f, "record {} (#{})", index, no),
+            RecordId::Index { index, no: None } => write!(f, "record {}", index),
+            RecordId::Key(key) => write!(f, "record \"{}\"", key),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DataError {
+    /// data directory from cli flag or env var does not exist
+    DirNotFound {
+        dir: PathBuf,
+        from: &'static str,
+    },
+    Io {
+        file: PathBuf,
+        source: io::Error,
+    },
+    /// not valid json, or the top level has a wrong shape
+    Syntax {
+        file: PathBuf,
+        source: serde_json::Error,
+    },
+    /// a record failed to deserialize
+    Record {
+        file: PathBuf,
+        record: RecordId,
+        field: String,
+        message: String,
+    },
+    /// more than one record of a file failed to deserialize
+    Records(Vec<DataError>),
+    MissingManifest {
+        file: PathBuf,
+    },
+    Schema {
+        file: PathBuf,
+        found: u32,
+        expected: u32,
+    },
+    DuplicateNumber {
+        no: u16,
+    },
+    UnknownAbility {
+        no: u16,
+        id: u16,
+    },
+    UnknownPokemon {
+        file: PathBuf,
+        no: u16,
+    },
+    MissingArt {
+        name: String,
+        path: PathBuf,
+    },
+}
+
+impl DataError {
+    /// split a deserialize error into the record it happened in and the field path inside it
+    pub fn from_path_error(
+        file: PathBuf,
+        err: serde_path_to_error::Error<serde_json::Error>,
+    ) -> Self {
+        let mut segments = err.path().iter();
+
+        let record = match segments.next() {
+            Some(Segment::Seq { index }) => RecordId::Index {
+                index: *index,
+                no: None,
+            },
+            Some(Segment::Map { key }) => RecordId::Key(key.clone()),
+            _ => {
+                return DataError::Syntax {
+                    file,
+                    source: err.into_inner(),
+                }
+            }
+        };
+
+        DataError::Record {
+            file,
+            record,
+            field: join_segments(segments),
+            message: err.into_inner().to_string(),
+        }
+    }
+
+    /// one error for every record in `errors`, `None` if there are none
+    pub fn from_records(mut errors: Vec<DataError>) -> Option<Self> {
+        match errors.len() {
+            0 => None,
+            1 => errors.pop(),
+            _ => Some(DataError::Records(errors)),
+        }
+    }
+
+    /// every problem this error stands for
+    pub fn into_problems(self) -> Vec<DataError> {
+        match self {
+            DataError::Records(errors) => errors,
+            e => vec![e],
+        }
+    }
+
+    /// error of deserializing a single record
+    pub fn from_record_error(
+        file: PathBuf,
+        record: RecordId,
+        err: serde_path_to_error::Error<serde_json::Error>,
+    ) -> Self {
+        DataError::Record {
+            file,
+            record,
+            field: join_segments(err.path().iter()),
+            message: err.into_inner().to_string(),
+        }
+    }
+}
+
+fn join_segments<'a>(segments: impl Iterator<Item = &'a Segment>) -> String {
+    segments
+        .map(|s| match s {
+            Segment::Seq { index } => index.to_string(),
+            Segment::Map { key } => key.clone(),
+            Segment::Enum { variant } => variant.clone(),
+            Segment::Unknown => String::from("?"),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+impl Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DataError::*;
+
+        match self {
+            DirNotFound { dir, from } => {
+                write!(
+                    f,
+                    "data directory {} from {} does not exist",
+                    dir.display(),
+                    from
+                )
+            }
+            Io { file, source } => write!(f, "{}: {}", file.display(), source),
+            Syntax { file, source } => write!(f, "{}: {}", file.display(), source),
+            Record {
+                file,
+                record,
+                field,
+                message,
+            } => {
+                if field.is_empty() {
+                    write!(f, "{}: {}: {}", file.display(), record, message)
+                } else {
+                    write!(
+                        f,
+                        "{}: {}, field `{}`: {}",
+                        file.display(),
+                        record,
+                        field,
+                        message
+                    )
+                }
+            }
+            Records(errors) => {
+                let lines = errors.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+                write!(f, "{}", lines.join("\n"))
+            }
+            MissingManifest { file } => write!(
+                f,
+                "{} is missing, data directories need it to declare the data schema",
+                file.display()
+            ),
+            Schema {
+                file,
+                found,
+                expected,
+            } => write!(
+                f,
+                "{} declares data schema {}, but this poketex supports schema {}; update {}",
+                file.display(),
+                found,
+                expected,
+                if found > expected {
+                    "poketex"
+                } else {
+                    "the data files"
+                }
+            ),
+            DuplicateNumber { no } => write!(f, "pokemon #{} is defined more than once", no),
+            UnknownAbility { no, id } => {
+                write!(f, "pokemon #{} refers to unknown ability {}", no, id)
+            }
+            UnknownPokemon { file, no } => {
+                write!(f, "{}: refers to unknown pokemon #{}", file.display(), no)
+            }
+            MissingArt { name, path } => {
+                write!(f, "art of {} is missing, expected {}", name, path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DataError::Io { source, .. } => Some(source),
+            DataError::Syntax { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,358 @@
+mod archive;
+mod assets;
+mod error;
+mod overlay;
+mod validate;
+
+use std::{
+    collections::HashMap,
+    fs,
+    hash::Hash,
+    path::{Path, PathBuf},
+    rc::Rc,
+    str::FromStr,
+};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+
+#[cfg(unix)]
+use xdg::BaseDirectories;
+
+use crate::pokemon::{
+    ascii_form::AsciiJson, AbilityMap, DexEntryMap, PokemonBundle, PokemonEntity, RegionalDexList,
+};
+
+pub use archive::{pack, ArtArchive, ART_ARCHIVE_FILE};
+pub use assets::{get_install_dirs, Assets, Probe, ART_DIR};
+pub use error::{DataError, DataResult, RecordId};
+pub use overlay::{get_overlay_paths, Overlay, OverlayConflict};
+pub use validate::validate;
+
+use self::overlay::{OverlayMerger, PokemonRecord};
+
+/// bump when the layout of data files changes in an incompatible way
+pub const DATA_SCHEMA_VERSION: u32 = 1;
+pub static DATA_DIR_ENV: &str = "POKETEX_DATA";
+static MANIFEST_FILE: &str = "manifest.json";
+
+#[derive(Deserialize)]
+struct Manifest {
+    schema: u32,
+}
+
+pub struct PokemonData {
+    pub pokemon: Vec<PokemonEntity>,
+    pub ability: AbilityMap,
+    pub dex: DexEntryMap,
+    pub regional: RegionalDexList,
+    pub ascii: AsciiJson,
+    pub conflicts: Vec<OverlayConflict>,
+}
+
+impl PokemonData {
+    /// load data files from `dir`, any file missing there falls back to the embedded one,
+    /// then merge `overlays` over it in order
+    ///
+    /// the schema of `dir` is checked by `get_data_dir_path`
+    pub fn load(dir: Option<&Path>, overlays: &[PathBuf]) -> DataResult<Self> {
+        // keep going past a bad record so all of them are reported
+        let mut errors = vec![];
+
+        let (pokemon_file, pokemon): (_, Vec<Value>) =
+            read_file(dir, "data.json", include_str!("../../data/data.json"))?;
+        let mut pokemon = pokemon
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| PokemonRecord {
+                file: pokemon_file.clone(),
+                index,
+                value,
+            })
+            .collect::<Vec<_>>();
+        let mut ability: AbilityMap = read_map(
+            dir,
+            "ability.json",
+            include_str!("../../data/ability.json"),
+            &mut errors,
+        )?;
+        let mut ascii: AsciiJson = read_list(
+            dir,
+            "ascii.json",
+            include_str!("../../data/ascii.json"),
+            &mut errors,
+        )?;
+
+        let mut merger = OverlayMerger::default();
+        for path in overlays {
+            merger.merge(Overlay::read(path)?, &mut pokemon, &mut ability, &mut ascii)?;
+        }
+
+        let mut records = Vec::with_capacity(pokemon.len());
+        for PokemonRecord { file, index, value } in pokemon {
+            let no = value["no"].as_u64();
+            match serde_path_to_error::deserialize::<_, PokemonEntity>(value) {
+                Ok(pm) => records.push(pm),
+                Err(e) => {
+                    let mut e =
+                        DataError::from_record_error(file, RecordId::Index { index, no }, e);
+                    // a field set by a patch is the fault of the overlay
+                    if let DataError::Record {
+                        file,
+                        record,
+                        field,
+                        ..
+                    } = &mut e
+                    {
+                        if let Some((patch, index)) = no.and_then(|no| merger.patched_by(no, field))
+                        {
+                            *file = patch;
+                            *record = RecordId::Index { index, no };
+                        }
+                    }
+                    errors.push(e);
+                }
+            }
+        }
+        records.sort_by_key(|p| p.no);
+
+        let dex = read_map(
+            dir,
+            "dex.json",
+            include_str!("../../data/dex.json"),
+            &mut errors,
+        )?;
+        let regional = read_list(
+            dir,
+            "regional.json",
+            include_str!("../../data/regional.json"),
+            &mut errors,
+        )?;
+
+        if let Some(e) = DataError::from_records(errors) {
+            return Err(e);
+        }
+
+        Ok(Self {
+            pokemon: records,
+            ability,
+            dex,
+            regional,
+            ascii,
+            conflicts: merger.conflicts,
+        })
+    }
+
+    pub fn embedded() -> DataResult<Self> {
+        Self::load(None, &[])
+    }
+
+    pub fn bundle(self) -> (PokemonBundle, AsciiJson) {
+        let bundle = PokemonBundle {
+            ability: Rc::new(self.ability),
+            dex: Rc::new(self.dex),
+            regional: Rc::new(self.regional),
+            pokemon: self.pokemon.into_iter().map(Rc::new).collect(),
+        };
+
+        (bundle, self.ascii)
+    }
+}
+
+/// art archive built into the binary with the `embed-art` feature
+#[cfg(feature = "embed-art")]
+pub fn embedded_art() -> Option<ArtArchive> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/colorscripts.art"));
+    ArtArchive::from_bytes(std::borrow::Cow::Borrowed(bytes)).ok()
+}
+
+#[cfg(not(feature = "embed-art"))]
+pub fn embedded_art() -> Option<ArtArchive> {
+    None
+}
+
+/// data dir from cli flag, then env var, then xdg data home
+///
+/// the first two need a manifest, the xdg one without it is taken as the current schema
+pub fn get_data_dir_path(flag: Option<PathBuf>) -> DataResult<Option<PathBuf>> {
+    if let Some(dir) = flag {
+        if !dir.is_dir() {
+            return Err(DataError::DirNotFound {
+                dir,
+                from: "--data",
+            });
+        }
+        check_schema(&dir)?;
+        return Ok(Some(dir));
+    }
+
+    if let Some(dir) = std::env::var_os(DATA_DIR_ENV) {
+        let dir = PathBuf::from(dir);
+        if !dir.is_dir() {
+            return Err(DataError::DirNotFound {
+                dir,
+                from: DATA_DIR_ENV,
+            });
+        }
+        check_schema(&dir)?;
+        return Ok(Some(dir));
+    }
+
+    #[cfg(unix)]
+    if let Ok(xdg_dir) = BaseDirectories::new() {
+        let data_dir = xdg_dir.get_data_home().join("poketex").join("data");
+        if data_dir.is_dir() {
+            return implicit_data_dir(data_dir);
+        }
+    };
+
+    #[cfg(windows)]
+    if let Ok(data_dir) = std::env::var("LOCALAPPDATA") {
+        let data_dir = Path::new(&data_dir).join("poketex").join("data");
+        if data_dir.is_dir() {
+            return implicit_data_dir(data_dir);
+        }
+    }
+
+    Ok(None)
+}
+
+/// data dir found without being asked for, its manifest is checked only if there is one
+fn implicit_data_dir(dir: PathBuf) -> DataResult<Option<PathBuf>> {
+    if dir.join(MANIFEST_FILE).exists() {
+        check_schema(&dir)?;
+    }
+    Ok(Some(dir))
+}
+
+fn check_schema(dir: &Path) -> DataResult<()> {
+    let file = dir.join(MANIFEST_FILE);
+    if !file.exists() {
+        return Err(DataError::MissingManifest { file });
+    }
+
+    let manifest: Manifest = parse(file.clone(), &read_text(&file)?)?;
+    if manifest.schema != DATA_SCHEMA_VERSION {
+        return Err(DataError::Schema {
+            file,
+            found: manifest.schema,
+            expected: DATA_SCHEMA_VERSION,
+        });
+    }
+
+    Ok(())
+}
+
+pub(crate) fn read_text(file: &Path) -> DataResult<String> {
+    fs::read_to_string(file).map_err(|source| DataError::Io {
+        file: file.to_path_buf(),
+        source,
+    })
+}
+
+pub(crate) fn parse<T: DeserializeOwned>(file: PathBuf, text: &str) -> DataResult<T> {
+    let de = &mut serde_json::Deserializer::from_str(text);
+    serde_path_to_error::deserialize(de).map_err(|e| DataError::from_path_error(file, e))
+}
+
+/// read `name` from `dir`, or the embedded one if it's not there
+fn read_file<T: DeserializeOwned>(
+    dir: Option<&Path>,
+    name: &str,
+    embedded: &str,
+) -> DataResult<(PathBuf, T)> {
+    if let Some(file) = dir.map(|dir| dir.join(name)).filter(|p| p.exists()) {
+        let text = read_text(&file)?;
+        return parse(file.clone(), &text).map(|data| (file, data));
+    }
+
+    let file = Path::new("(embedded)").join(name);
+    parse(file.clone(), embedded).map(|data| (file, data))
+}
+
+/// records of the array file `name`, bad ones are added to `errors` and left out
+fn read_list<T: DeserializeOwned>(
+    dir: Option<&Path>,
+    name: &str,
+    embedded: &str,
+    errors: &mut Vec<DataError>,
+) -> DataResult<Vec<T>> {
+    let (file, records): (_, Vec<Value>) = read_file(dir, name, embedded)?;
+
+    let mut list = Vec::with_capacity(records.len());
+    for (index, value) in records.into_iter().enumerate() {
+        let no = value["no"].as_u64();
+        match serde_path_to_error::deserialize(value) {
+            Ok(record) => list.push(record),
+            Err(e) => errors.push(DataError::from_record_error(
+                file.clone(),
+                RecordId::Index { index, no },
+                e,
+            )),
+        }
+    }
+
+    Ok(list)
+}
+
+/// records of the map file `name`, bad ones are added to `errors` and left out
+fn read_map<K: FromStr + Eq + Hash, T: DeserializeOwned>(
+    dir: Option<&Path>,
+    name: &str,
+    embedded: &str,
+    errors: &mut Vec<DataError>,
+) -> DataResult<HashMap<K, T>> {
+    let (file, records): (_, serde_json::Map<String, Value>) = read_file(dir, name, embedded)?;
+
+    let mut map = HashMap::with_capacity(records.len());
+    for (key, value) in records {
+        let Ok(id) = key.parse() else {
+            errors.push(DataError::Record {
+                file: file.clone(),
+                record: RecordId::Key(key),
+                field: String::new(),
+                message: String::from("key is not a number"),
+            });
+            continue;
+        };
+
+        match serde_path_to_error::deserialize(value) {
+            Ok(record) => {
+                map.insert(id, record);
+            }
+            Err(e) => errors.push(DataError::from_record_error(
+                file.clone(),
+                RecordId::Key(key),
+                e,
+            )),
+        }
+    }
+
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn implicit_data_dir_needs_no_manifest() {
+        let dir = std::env::temp_dir().join(format!("poketex-implicit-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        assert_eq!(implicit_data_dir(dir.clone()).ok(), Some(Some(dir.clone())));
+        assert!(matches!(
+            get_data_dir_path(Some(dir.clone())),
+            Err(DataError::MissingManifest { .. })
+        ));
+
+        // a manifest that is there is still checked
+        fs::write(dir.join(MANIFEST_FILE), r#"{"schema": 0}"#).unwrap();
+        assert!(matches!(
+            implicit_data_dir(dir.clone()),
+            Err(DataError::Schema { found: 0, .. })
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,314 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::pokemon::{ability::Ability, ascii_form::AsciiJson, AbilityMap};
+
+use super::{parse, read_text, DataError, DataResult, RecordId, DATA_SCHEMA_VERSION};
+
+static OVERLAY_DIR: &str = "overlays";
+
+/// extra data merged over the built-in data, e.g. species of a fangame
+#[derive(Deserialize)]
+pub struct Overlay {
+    schema: u32,
+    /// new pokemon records, a record with an existing number replaces it
+    #[serde(default)]
+    pokemon: Vec<Value>,
+    #[serde(default)]
+    ability: HashMap<u16, Ability>,
+    /// partial pokemon records matched by `no`, fields are merged into the existing record
+    #[serde(default)]
+    patch: Vec<Value>,
+    #[serde(default)]
+    ascii: AsciiJson,
+    /// art directory with `regular` and `shiny` folders, relative to the overlay file
+    #[serde(default)]
+    art: Option<PathBuf>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+/// a pokemon record and the file and position it was read from
+pub struct PokemonRecord {
+    pub file: PathBuf,
+    pub index: usize,
+    pub value: Value,
+}
+
+pub enum OverlayConflict {
+    PokemonReplaced {
+        file: PathBuf,
+        no: u64,
+    },
+    AbilityReplaced {
+        file: PathBuf,
+        id: u16,
+    },
+    AsciiReplaced {
+        file: PathBuf,
+        name: String,
+    },
+    PatchTargetMissing {
+        file: PathBuf,
+        no: u64,
+    },
+    FieldPatchedTwice {
+        file: PathBuf,
+        no: u64,
+        field: String,
+        previous: PathBuf,
+    },
+}
+
+impl Display for OverlayConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use OverlayConflict::*;
+
+        match self {
+            PokemonReplaced { file, no } => {
+                write!(
+                    f,
+                    "{}: pokemon #{} replaces an existing record",
+                    file.display(),
+                    no
+                )
+            }
+            AbilityReplaced { file, id } => {
+                write!(
+                    f,
+                    "{}: ability {} replaces an existing ability",
+                    file.display(),
+                    id
+                )
+            }
+            AsciiReplaced { file, name } => {
+                write!(
+                    f,
+                    "{}: art forms of {} replace existing forms",
+                    file.display(),
+                    name
+                )
+            }
+            PatchTargetMissing { file, no } => {
+                write!(
+                    f,
+                    "{}: patch target pokemon #{} does not exist, skipped",
+                    file.display(),
+                    no
+                )
+            }
+            FieldPatchedTwice {
+                file,
+                no,
+                field,
+                previous,
+            } => write!(
+                f,
+                "{}: field `{}` of pokemon #{} was already patched by {}",
+                file.display(),
+                field,
+                no,
+                previous.display()
+            ),
+        }
+    }
+}
+
+impl Overlay {
+    pub fn read(path: &Path) -> DataResult<Self> {
+        let mut overlay: Overlay = parse(path.to_path_buf(), &read_text(path)?)?;
+
+        if overlay.schema != DATA_SCHEMA_VERSION {
+            return Err(DataError::Schema {
+                file: path.to_path_buf(),
+                found: overlay.schema,
+                expected: DATA_SCHEMA_VERSION,
+            });
+        }
+
+        overlay.path = path.to_path_buf();
+        Ok(overlay)
+    }
+}
+
+/// overlay files in `<data dir>/overlays` sorted by name, followed by `extra`
+pub fn get_overlay_paths(dir: Option<&Path>, extra: &[PathBuf]) -> DataResult<Vec<PathBuf>> {
+    let mut paths = vec![];
+
+    if let Some(overlay_dir) = dir.map(|d| d.join(OVERLAY_DIR)).filter(|d| d.is_dir()) {
+        let io_error = |source| DataError::Io {
+            file: overlay_dir.clone(),
+            source,
+        };
+
+        for entry in fs::read_dir(&overlay_dir).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+    }
+
+    paths.extend(extra.iter().cloned());
+    Ok(paths)
+}
+
+#[derive(Default)]
+pub struct OverlayMerger {
+    pub conflicts: Vec<OverlayConflict>,
+    /// overlay file and patch index of every patched field
+    patched: HashMap<(u64, String), (PathBuf, usize)>,
+}
+
+impl OverlayMerger {
+    pub fn merge(
+        &mut self,
+        overlay: Overlay,
+        pokemon: &mut Vec<PokemonRecord>,
+        ability: &mut AbilityMap,
+        ascii: &mut AsciiJson,
+    ) -> DataResult<()> {
+        let file = overlay.path;
+
+        for (index, record) in overlay.pokemon.into_iter().enumerate() {
+            let no = record_no(&record, &file, index)?;
+            let record = PokemonRecord {
+                file: file.clone(),
+                index,
+                value: record,
+            };
+            match pokemon
+                .iter_mut()
+                .find(|p| p.value["no"].as_u64() == Some(no))
+            {
+                Some(old) => {
+                    *old = record;
+                    self.conflicts.push(OverlayConflict::PokemonReplaced {
+                        file: file.clone(),
+                        no,
+                    });
+                }
+                None => pokemon.push(record),
+            }
+        }
+
+        for (index, record) in overlay.patch.into_iter().enumerate() {
+            let no = record_no(&record, &file, index)?;
+            let Some(target) = pokemon
+                .iter_mut()
+                .find(|p| p.value["no"].as_u64() == Some(no))
+            else {
+                self.conflicts.push(OverlayConflict::PatchTargetMissing {
+                    file: file.clone(),
+                    no,
+                });
+                continue;
+            };
+
+            let Value::Object(fields) = record else {
+                continue;
+            };
+
+            for (field, value) in fields.into_iter().filter(|(k, _)| k != "no") {
+                for path in leaf_paths(&field, &value) {
+                    let source = (file.clone(), index);
+                    if let Some((previous, _)) = self.patched.insert((no, path.clone()), source) {
+                        self.conflicts.push(OverlayConflict::FieldPatchedTwice {
+                            file: file.clone(),
+                            no,
+                            field: path,
+                            previous,
+                        });
+                    }
+                }
+
+                if let Value::Object(target) = &mut target.value {
+                    merge_value(target, field, value);
+                }
+            }
+        }
+
+        for (id, a) in overlay.ability {
+            if ability.insert(id, a).is_some() {
+                self.conflicts.push(OverlayConflict::AbilityReplaced {
+                    file: file.clone(),
+                    id,
+                });
+            }
+        }
+
+        let art = overlay
+            .art
+            .map(|art| file.parent().unwrap_or(Path::new(".")).join(art));
+
+        for mut item in overlay.ascii {
+            item.root = art.clone().or(item.root);
+            match ascii.iter_mut().find(|a| a.name == item.name) {
+                Some(old) => {
+                    self.conflicts.push(OverlayConflict::AsciiReplaced {
+                        file: file.clone(),
+                        name: item.name.clone(),
+                    });
+                    *old = item;
+                }
+                None => ascii.push(item),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl OverlayMerger {
+    /// overlay file and index of the patch that set `field` of pokemon `no`, or a field it is in
+    pub fn patched_by(&self, no: u64, field: &str) -> Option<(PathBuf, usize)> {
+        self.patched
+            .iter()
+            .find(|((n, path), _)| {
+                *n == no && (field == path || field.starts_with(&format!("{}.", path)))
+            })
+            .map(|(_, source)| source.clone())
+    }
+}
+
+fn record_no(record: &Value, file: &Path, index: usize) -> DataResult<u64> {
+    record["no"].as_u64().ok_or_else(|| DataError::Record {
+        file: file.to_path_buf(),
+        record: RecordId::Index { index, no: None },
+        field: String::from("no"),
+        message: String::from("missing pokemon number"),
+    })
+}
+
+/// dotted paths of the non-object values, e.g. `iv.hp`
+fn leaf_paths(prefix: &str, value: &Value) -> Vec<String> {
+    match value {
+        Value::Object(map) if !map.is_empty() => map
+            .iter()
+            .flat_map(|(k, v)| leaf_paths(&format!("{}.{}", prefix, k), v))
+            .collect(),
+        _ => vec![prefix.to_string()],
+    }
+}
+
+/// objects are merged recursively, other values are replaced
+fn merge_value(target: &mut Map<String, Value>, field: String, value: Value) {
+    match (target.get_mut(&field), value) {
+        (Some(Value::Object(old)), Value::Object(new)) => {
+            for (k, v) in new {
+                merge_value(old, k, v);
+            }
+        }
+        (_, value) => {
+            target.insert(field, value);
+        }
+    }
+}
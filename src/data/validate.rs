@@ -0,0 +1,200 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    path::{Path, PathBuf},
+};
+
+use crate::state::art::ArtKey;
+
+use super::{check_schema, get_overlay_paths, ArtArchive, DataError, PokemonData};
+
+/// check a data directory with its overlays and return every problem found,
+/// `assets` is the colorscripts directory art is looked up in, then `archive`
+pub fn validate(dir: &Path, assets: &Path, archive: Option<&ArtArchive>) -> Vec<DataError> {
+    let data = match check_schema(dir)
+        .and_then(|_| get_overlay_paths(Some(dir), &[]))
+        .and_then(|overlays| PokemonData::load(Some(dir), &overlays))
+    {
+        Ok(data) => data,
+        Err(e) => return e.into_problems(),
+    };
+
+    let mut problems = vec![];
+
+    let mut numbers = HashSet::new();
+    for pm in data.pokemon.iter() {
+        if !numbers.insert(pm.no) {
+            problems.push(DataError::DuplicateNumber { no: pm.no });
+        }
+    }
+
+    for pm in data.pokemon.iter() {
+        let form_ability = pm.form.iter().flatten().flat_map(|f| f.ability.iter());
+        let unknown = pm
+            .ability
+            .iter()
+            .chain(form_ability)
+            .filter(|id| !data.ability.contains_key(id))
+            .collect::<BTreeSet<_>>();
+
+        problems.extend(
+            unknown
+                .into_iter()
+                .map(|id| DataError::UnknownAbility { no: pm.no, id: *id }),
+        );
+    }
+
+    let dex = data.dex.keys().collect::<BTreeSet<_>>();
+    let regional = data.regional.iter().flat_map(|r| r.pokemon.iter());
+    for (file, no) in dex
+        .into_iter()
+        .map(|no| ("dex.json", no))
+        .chain(regional.map(|no| ("regional.json", no)))
+    {
+        if !numbers.contains(no) {
+            problems.push(DataError::UnknownPokemon {
+                file: PathBuf::from(file),
+                no: *no,
+            });
+        }
+    }
+
+    for item in data.ascii.iter() {
+        let root = item.root.as_deref().unwrap_or(assets);
+        for form in item.forms.iter() {
+            for shiny in [false, true] {
+                let key = ArtKey {
+                    name: item.name.clone(),
+                    form: form.clone(),
+                    shiny,
+                    root: root.to_path_buf(),
+                };
+                if !key.path().exists() && !archive.is_some_and(|a| a.contains(&key.entry())) {
+                    problems.push(DataError::MissingArt {
+                        name: key.file(),
+                        path: key.path(),
+                    });
+                }
+            }
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, fs};
+
+    use super::*;
+    use crate::data::pack;
+
+    #[test]
+    fn every_bad_record_is_reported() {
+        let dir = std::env::temp_dir().join(format!("poketex-validate-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("manifest.json"), r#"{"schema": 1}"#).unwrap();
+        fs::write(
+            dir.join("data.json"),
+            r#"[
+                {"no": 1, "name": {"en": "Bulbasaur"}, "type": ["grass"], "iv": {"hp": 45, "att": 49, "def": 49, "s_att": 65, "s_def": 65, "spd": 45}, "ability": []},
+                {"no": 2, "name": {"en": "Ivysaur"}, "type": ["wood"], "iv": {"hp": 60, "att": 62, "def": 63, "s_att": 80, "s_def": 80, "spd": 60}, "ability": []},
+                {"no": 3, "name": {"en": "Venusaur"}, "type": ["grass"], "iv": {"hp": "high"}, "ability": []}
+            ]"#,
+        )
+        .unwrap();
+
+        let problems = validate(&dir, &dir, None);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let records = problems
+            .iter()
+            .filter_map(|p| match p {
+                DataError::Record { record, .. } => Some(record.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(records, ["record 1 (#2)", "record 2 (#3)"]);
+    }
+
+    #[test]
+    fn records_are_reported_against_their_file() {
+        let dir = std::env::temp_dir().join(format!("poketex-blame-{}", std::process::id()));
+        fs::create_dir_all(dir.join("overlays")).unwrap();
+        fs::write(dir.join("manifest.json"), r#"{"schema": 1}"#).unwrap();
+        fs::write(
+            dir.join("overlays/fakemon.json"),
+            r#"{
+                "schema": 1,
+                "pokemon": [{"no": 2000, "name": {"en": "Fakemon"}, "type": ["normal"], "iv": {"hp": "x"}, "ability": []}],
+                "patch": [{"no": 4, "iv": {"hp": "high"}}]
+            }"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("dex.json"),
+            r#"{"1": [{"version": "red"}], "4": "none"}"#,
+        )
+        .unwrap();
+
+        let problems = validate(&dir, &dir, None);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let mut records = problems
+            .iter()
+            .filter_map(|p| match p {
+                DataError::Record {
+                    file,
+                    record,
+                    field,
+                    ..
+                } => Some(format!(
+                    "{} {} {}",
+                    file.file_name().unwrap().to_string_lossy(),
+                    record,
+                    field
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        records.sort();
+        assert_eq!(
+            records,
+            [
+                "dex.json record \"1\" 0",
+                "dex.json record \"4\" ",
+                "fakemon.json record 0 (#2000) iv.hp",
+                "fakemon.json record 0 (#4) iv.hp",
+            ]
+        );
+    }
+
+    #[test]
+    fn art_in_the_archive_is_not_missing() {
+        let dir = std::env::temp_dir().join(format!("poketex-validate-art-{}", std::process::id()));
+        for colors in ["regular", "shiny"] {
+            fs::create_dir_all(dir.join("art/small").join(colors)).unwrap();
+            fs::write(dir.join("art/small").join(colors).join("pikachu"), "art").unwrap();
+        }
+        fs::write(dir.join("manifest.json"), r#"{"schema": 1}"#).unwrap();
+        fs::write(
+            dir.join("ascii.json"),
+            r#"[{"name": "pikachu", "forms": ["regular"]}]"#,
+        )
+        .unwrap();
+        let archive = ArtArchive::from_bytes(Cow::Owned(pack(&dir.join("art")).unwrap())).unwrap();
+
+        // no art directory, only the archive has the art
+        let assets = dir.join("small");
+        let missing = |problems: Vec<DataError>| {
+            problems
+                .iter()
+                .filter(|p| matches!(p, DataError::MissingArt { .. }))
+                .count()
+        };
+        let without = missing(validate(&dir, &assets, None));
+        let with = missing(validate(&dir, &assets, Some(&archive)));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!((without, with), (2, 0));
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    collections::VecDeque,
+    io,
+    time::{Duration, Instant},
+};
+
+use ratatui::crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind,
+};
+
+pub enum AppEvent {
+    Input(Event),
+    /// nothing happened before the timeout
+    Tick,
+}
+
+pub trait EventSource {
+    /// next event, a tick when none arrives within `timeout`, `None` when the source has no more
+    /// events
+    fn next(&mut self, timeout: Duration) -> io::Result<Option<AppEvent>>;
+
+    /// time of the last event, for timers
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// events from the terminal
+pub struct TerminalEvents;
+
+impl EventSource for TerminalEvents {
+    fn next(&mut self, timeout: Duration) -> io::Result<Option<AppEvent>> {
+        if event::poll(timeout)? {
+            event::read().map(|e| Some(AppEvent::Input(e)))
+        } else {
+            Ok(Some(AppEvent::Tick))
+        }
+    }
+}
+
+/// a fixed sequence of events, for driving the app without a terminal
+///
+/// the clock only moves on `then_wait`, so timers are deterministic
+pub struct ScriptedEvents {
+    /// time passed before each event
+    events: VecDeque<(Duration, AppEvent)>,
+    now: Instant,
+}
+
+impl Default for ScriptedEvents {
+    fn default() -> Self {
+        Self {
+            events: VecDeque::new(),
+            now: Instant::now(),
+        }
+    }
+}
+
+impl ScriptedEvents {
+    pub fn new(events: impl IntoIterator<Item = Event>) -> Self {
+        events.into_iter().fold(Self::default(), Self::then)
+    }
+
+    /// each char is pressed as a key without modifiers
+    pub fn keys(keys: &str) -> Self {
+        Self::default().then_keys(keys)
+    }
+
+    pub fn then_keys(self, keys: &str) -> Self {
+        keys.chars().fold(self, |events, c| {
+            events.then_key(KeyCode::Char(c), KeyModifiers::NONE)
+        })
+    }
+
+    pub fn then_key(self, code: KeyCode, modifiers: KeyModifiers) -> Self {
+        self.then(Event::Key(KeyEvent::new(code, modifiers)))
+    }
+
+    pub fn then_mouse(self, kind: MouseEventKind, column: u16, row: u16) -> Self {
+        self.then(Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }))
+    }
+
+    pub fn then(mut self, event: Event) -> Self {
+        self.events
+            .push_back((Duration::ZERO, AppEvent::Input(event)));
+        self
+    }
+
+    /// let time pass, then tick
+    pub fn then_wait(mut self, duration: Duration) -> Self {
+        self.events.push_back((duration, AppEvent::Tick));
+        self
+    }
+
+    pub fn chain(mut self, events: ScriptedEvents) -> Self {
+        self.events.extend(events.events);
+        self
+    }
+}
+
+impl EventSource for ScriptedEvents {
+    fn next(&mut self, _timeout: Duration) -> io::Result<Option<AppEvent>> {
+        Ok(self.events.pop_front().map(|(duration, event)| {
+            self.now += duration;
+            event
+        }))
+    }
+
+    fn now(&self) -> Instant {
+        self.now
+    }
+}
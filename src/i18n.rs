@@ -0,0 +1,65 @@
+use std::{collections::HashMap, fmt::Display};
+
+use lazy_static::lazy_static;
+
+use crate::pokemon::Locale;
+
+/// ui texts of a locale, keyed like `stat.hp`
+pub type Catalog = HashMap<String, String>;
+
+lazy_static! {
+    static ref CATALOGS: HashMap<Locale, Catalog> = Locale::ALL
+        .into_iter()
+        .map(|locale| {
+            let catalog = serde_json::from_str(source(locale))
+                .unwrap_or_else(|e| panic!("i18n/{}.json: {}", locale, e));
+            (locale, catalog)
+        })
+        .collect();
+}
+
+fn source(locale: Locale) -> &'static str {
+    use Locale::*;
+
+    match locale {
+        En => include_str!("../data/i18n/en.json"),
+        Ja => include_str!("../data/i18n/ja.json"),
+        Zh => include_str!("../data/i18n/zh.json"),
+        ZhHans => include_str!("../data/i18n/zh-Hans.json"),
+        Ko => include_str!("../data/i18n/ko.json"),
+        Fr => include_str!("../data/i18n/fr.json"),
+        De => include_str!("../data/i18n/de.json"),
+        Es => include_str!("../data/i18n/es.json"),
+        It => include_str!("../data/i18n/it.json"),
+    }
+}
+
+pub fn catalog(locale: Locale) -> &'static Catalog {
+    &CATALOGS[&locale]
+}
+
+/// ui text of `key`, missing texts fall back like names do, and to the key at last
+pub fn tr(locale: Locale, key: &'static str) -> &'static str {
+    locale
+        .fallbacks()
+        .find_map(|l| catalog(l).get(key))
+        .map_or(key, String::as_str)
+}
+
+/// [`tr`] with each `{}` replaced by the next of `args`
+pub fn trf(locale: Locale, key: &'static str, args: &[&dyn Display]) -> String {
+    let mut args = args.iter();
+
+    tr(locale, key)
+        .split("{}")
+        .enumerate()
+        .fold(String::new(), |mut text, (i, part)| {
+            if i > 0 {
+                if let Some(arg) = args.next() {
+                    text.push_str(&arg.to_string());
+                }
+            }
+            text.push_str(part);
+            text
+        })
+}
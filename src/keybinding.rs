@@ -1,7 +1,13 @@
 use ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use tui_input::backend::crossterm::EventHandler;
+use tui_input::{backend::crossterm::EventHandler, Input};
 
-use crate::state::{AppState, InputMode};
+use crate::{
+    pokemon::Effectiveness,
+    state::{
+        command::{Command, CommandMessage},
+        AppState, InputMode,
+    },
+};
 
 static PAGE_NUM: u8 = 4;
 
@@ -35,9 +41,91 @@ fn on_editing(app: &mut AppState, event: KeyEvent) -> KeyHandleResult {
     KeyHandleResult::Continue
 }
 
+fn on_command(app: &mut AppState, event: KeyEvent) -> KeyHandleResult {
+    use KeyCode::*;
+
+    let command_line = &mut app.command_line;
+
+    match event.code {
+        Esc => {
+            command_line.reset();
+            app.tui.input_mode = InputMode::Normal;
+        }
+        Backspace if command_line.input.value().is_empty() => {
+            app.tui.input_mode = InputMode::Normal;
+        }
+        Enter => {
+            app.tui.input_mode = InputMode::Normal;
+            let line = command_line.submit();
+            if line.is_empty() {
+                return KeyHandleResult::Continue;
+            }
+
+            let result = match Command::parse(&line) {
+                Ok(Command::Quit) => return KeyHandleResult::Exit,
+                Ok(command) => app.execute(command),
+                Err(e) => Err(e),
+            };
+
+            app.set_message(match result {
+                Ok(message) => message.map(CommandMessage::Info),
+                Err(e) => Some(CommandMessage::Error(e.message(app.locale()))),
+            });
+        }
+        Tab => app.complete_command(false),
+        BackTab => app.complete_command(true),
+        Up => command_line.history_previous(),
+        Down => command_line.history_next(),
+        _ => {
+            command_line.reset_completion();
+            command_line.input.handle_event(&Event::Key(event));
+        }
+    }
+
+    KeyHandleResult::Continue
+}
+
+fn on_quiz(app: &mut AppState, event: KeyEvent) -> KeyHandleResult {
+    use KeyCode::*;
+
+    match event.code {
+        Esc => app.tui.input_mode = InputMode::Normal,
+        Enter if app.quiz.is_answered() => app.next_quiz(),
+        Enter => {
+            app.quiz.guess();
+        }
+        Tab => app.quiz.give_up(),
+        _ if app.quiz.is_answered() => (),
+        _ => {
+            app.quiz.input.handle_event(&Event::Key(event));
+        }
+    }
+
+    KeyHandleResult::Continue
+}
+
+fn on_trainer(app: &mut AppState, event: KeyEvent) -> KeyHandleResult {
+    use KeyCode::*;
+
+    match event.code {
+        Esc => app.tui.input_mode = InputMode::Normal,
+        Enter if app.trainer.is_answered() => app.next_trainer(),
+        // 1 to 6 pick 0x to 4x
+        Char(c @ '1'..='6') => {
+            let index = c as usize - '1' as usize;
+            app.trainer.answer(Effectiveness::ALL[index]);
+        }
+        _ => (),
+    }
+
+    KeyHandleResult::Continue
+}
+
 fn on_normal(app: &mut AppState, event: KeyEvent) -> KeyHandleResult {
     use KeyCode::*;
 
+    app.set_message(None);
+
     let KeyEvent {
         code,
         modifiers,
@@ -50,16 +138,11 @@ fn on_normal(app: &mut AppState, event: KeyEvent) -> KeyHandleResult {
         (c, KeyModifiers::CONTROL) => match c {
             Char('f') => app.pokemon_list.scroll_down(PAGE_NUM),
             Char('b') => app.pokemon_list.scroll_up(PAGE_NUM),
+            Char('e') => app.pokemon_list.dex_scrollbar_state.scroll_down(),
+            Char('y') => app.pokemon_list.dex_scrollbar_state.scroll_up(),
             _ => return KeyHandleResult::Continue,
         },
 
-        // handle key with alt
-        (c, KeyModifiers::ALT) => match c {
-            Char('j') => app.pokemon_list.desc_scrollbar_state.scroll_down(),
-            Char('k') => app.pokemon_list.desc_scrollbar_state.scroll_up(),
-            _ => (),
-        },
-
         // handle other key
         (c, _) => match c {
             Char('q') => return KeyHandleResult::Exit,
@@ -67,24 +150,49 @@ fn on_normal(app: &mut AppState, event: KeyEvent) -> KeyHandleResult {
             Char('E') => app.tui.toggle_show_list(),
             Char('A') => app.tui.toggle_show_abilities(),
             Char('V') => app.tui.toggle_show_iv(),
+            Char('I') => app.tui.toggle_show_info(),
+            Char('D') => app.tui.toggle_show_dex(),
+            Char('C') => app.tui.toggle_show_list_column(),
+
+            Tab => app.tui.focus_next(false),
+            BackTab => app.tui.focus_next(true),
 
-            Down | Char('j') => app.pokemon_list.next(),
+            Down | Char('j') => app.scroll_focused(true),
             PageDown => app.pokemon_list.scroll_down(PAGE_NUM),
 
-            Up | Char('k') => app.pokemon_list.previous(),
+            Up | Char('k') => app.scroll_focused(false),
             PageUp => app.pokemon_list.scroll_up(PAGE_NUM),
 
             Left | Char('h') => app.pokemon_list.previous_profile_page(),
             Right | Char('l') => app.pokemon_list.next_profile_page(),
 
             Char('f') => app.pokemon_list.increase_ascii_form_index(),
+            Char('S') => app.pokemon_list.toggle_shiny(),
+            Char(']') => app.pokemon_list.next_dex_version(),
+            Char('[') => app.pokemon_list.previous_dex_version(),
             Char('/') => app.tui.input_mode = InputMode::Editing,
+            Char(':') => app.tui.input_mode = InputMode::Command,
+            Char('b') => app.pick_breeding(),
+            Char('R') => app.pokemon_list.next_regional_dex(),
+            Char('L') => app.next_locale(),
+            Char('M') => app.start_trainer(),
+            Char('Q') => {
+                if let Err(e) = app.start_quiz(app.quiz.generations.clone()) {
+                    let message = e.message(app.locale());
+                    app.set_message(Some(CommandMessage::Error(message)));
+                }
+            }
 
             Home => app.pokemon_list.scroll_to_first(),
             End => app.pokemon_list.scroll_to_end(),
 
             Char(c) => app.command(c),
-            Enter | Esc => app.reset_command(),
+            Esc => {
+                app.breeding.reset();
+                app.compare.reset();
+                app.reset_command()
+            }
+            Enter => app.reset_command(),
             _ => (),
         },
     }
@@ -92,6 +200,39 @@ fn on_normal(app: &mut AppState, event: KeyEvent) -> KeyHandleResult {
     KeyHandleResult::Continue
 }
 
+/// pasted text goes into the search box, or the command line while typing a command
+pub fn handle_paste(app: &mut AppState, text: &str) {
+    let text = text.replace(['\r', '\n'], " ");
+
+    match app.tui.input_mode {
+        InputMode::Command => {
+            let command_line = &mut app.command_line;
+            command_line.reset_completion();
+            command_line.input = insert(&command_line.input, &text);
+        }
+        InputMode::Quiz => app.quiz.input = insert(&app.quiz.input, &text),
+        InputMode::Trainer => (),
+        InputMode::Normal | InputMode::Editing => {
+            app.tui.input_mode = InputMode::Editing;
+            app.key_handle.input = insert(&app.key_handle.input, &text);
+            app.pokemon_list
+                .set_list_filter(app.key_handle.input.value().to_string());
+        }
+    }
+}
+
+/// `text` inserted at the cursor
+fn insert(input: &Input, text: &str) -> Input {
+    let value = input.value();
+    let at = value
+        .char_indices()
+        .nth(input.cursor())
+        .map_or(value.len(), |(i, _)| i);
+
+    Input::new(format!("{}{}{}", &value[..at], text, &value[at..]))
+        .with_cursor(input.cursor() + text.chars().count())
+}
+
 pub fn handle_key(app: &mut AppState, event: KeyEvent) -> KeyHandleResult {
     use KeyHandleResult::*;
 
@@ -101,6 +242,9 @@ pub fn handle_key(app: &mut AppState, event: KeyEvent) -> KeyHandleResult {
 
     match app.tui.input_mode {
         InputMode::Editing => on_editing(app, event),
+        InputMode::Command => on_command(app, event),
+        InputMode::Quiz => on_quiz(app, event),
+        InputMode::Trainer => on_trainer(app, event),
         InputMode::Normal => on_normal(app, event),
     }
 }
@@ -1,6 +1,12 @@
+pub mod app;
+pub mod data;
 pub mod env;
+pub mod event;
+pub mod i18n;
 pub mod keybinding;
+pub mod mouse;
 pub mod pokemon;
+pub mod sprite;
 pub mod state;
 pub mod ui;
 pub mod widget;
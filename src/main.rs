@@ -8,34 +8,81 @@ use std::{
 #[cfg(unix)]
 use xdg::BaseDirectories;
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use poketex::{
-    env::DEF_LOCALES,
-    keybinding::handle_key,
-    pokemon::{
-        ascii_form::{AsciiForms, AsciiJson},
-        AbilityMap, PokemonBundle, PokemonEntity,
+    app::run_app,
+    data::{
+        get_data_dir_path, get_install_dirs, get_overlay_paths, pack, validate, Assets, PokemonData,
     },
-    state::{tui::TuiState, AppState, PokemonListState},
-    ui::ui,
+    event::TerminalEvents,
+    pokemon::{ascii_form::AsciiForms, AltName, Locale},
+    sprite::{ImageProtocol, SpriteRenderer},
+    state::{quiz::QuizState, trainer::TrainerState, tui::TuiState, AppState, PokemonListState},
 };
 use ratatui::crossterm::{
-    event::{self, DisableMouseCapture, Event},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
-use ratatui::{
-    backend::{Backend, CrosstermBackend},
-    Terminal,
+    terminal::{
+        disable_raw_mode, enable_raw_mode, window_size, EnterAlternateScreen, LeaveAlternateScreen,
+    },
 };
-use serde_json::from_str;
+use ratatui::{backend::CrosstermBackend, Terminal};
 
 #[derive(Parser)]
 #[command(author, version)]
 pub struct Args {
-    /// locales [zh, ja, en]
-    #[arg(short, long, default_value=&"en")]
-    pub locale: String,
+    /// locale [en, ja, zh, zh-Hans, ko, fr, de, es, it]
+    #[arg(short, long, default_value = "en", value_parser = parse_locale)]
+    pub locale: Locale,
+
+    /// names also shown under the name, e.g. `ja,romaji`; locale codes or romaji
+    #[arg(long, value_delimiter = ',', value_parser = parse_alt_name)]
+    pub also_show: Vec<AltName>,
+
+    /// show the first of --also-show as a second column of the list
+    #[arg(long)]
+    pub list_column: bool,
+
+    /// directory of data files, overrides POKETEX_DATA and the xdg data dir
+    #[arg(short, long)]
+    pub data: Option<PathBuf>,
+
+    /// overlay file merged over the data, can be repeated
+    #[arg(short, long)]
+    pub overlay: Vec<PathBuf>,
+
+    /// directory of art files with `regular` and `shiny` in it, overrides the search
+    #[arg(long)]
+    pub assets: Option<PathBuf>,
+
+    /// png sprites with `regular` and `shiny` in it, drawn instead of the art
+    #[arg(long, value_name = "DIR")]
+    pub sprites: Option<PathBuf>,
+
+    /// image protocol of sprites [kitty, iterm2, sixel], detected by default
+    #[arg(long, value_name = "PROTOCOL", value_parser = parse_image_protocol)]
+    pub image_protocol: Option<ImageProtocol>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Check a data directory without starting the ui
+    Validate {
+        /// data directory to check
+        dir: PathBuf,
+    },
+    /// Pack a colorscripts directory into one art archive
+    Pack {
+        /// colorscripts directory, with `small` and `large` in it
+        dir: PathBuf,
+        /// archive to write, put it in the data dir as colorscripts.art
+        output: PathBuf,
+    },
+    /// Show where art is looked for and which art is missing
+    Doctor,
 }
 
 struct Tui {
@@ -47,7 +94,12 @@ impl Tui {
         // setup terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen)?;
+        execute!(
+            stdout,
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableBracketedPaste
+        )?;
         let backend = CrosstermBackend::new(stdout);
         Ok(Self {
             terminal: Terminal::new(backend)?,
@@ -62,7 +114,8 @@ impl Drop for Tui {
             let _ = execute!(
                 self.terminal.backend_mut(),
                 LeaveAlternateScreen,
-                DisableMouseCapture
+                DisableMouseCapture,
+                DisableBracketedPaste
             );
             let _ = disable_raw_mode();
             let _ = self.terminal.show_cursor();
@@ -72,105 +125,199 @@ impl Drop for Tui {
 
 fn main() -> Result<()> {
     let args = Args::parse();
-    let assets_dir = get_assets_dir_path()?;
+    let data_dir = get_data_dir_path(args.data);
+    let mut assets = Assets::find(
+        args.assets.clone(),
+        data_dir.as_ref().ok().and_then(|dir| dir.as_deref()),
+        &get_install_dirs(),
+    );
 
-    unsafe {
-        DEF_LOCALES = Box::leak(args.locale.into_boxed_str());
+    match args.command {
+        Some(Command::Validate { dir }) => run_validate(&dir, &assets),
+        Some(Command::Pack { dir, output }) => run_pack(&dir, &output),
+        Some(Command::Doctor) | None => (),
     }
 
-    let Ok((pokemon, ability, ascii)) = load_data() else {
-        println!("load data error");
-        std::process::exit(2);
-    };
+    // the doctor reports it instead
+    if let (None, Some(dir)) = (&args.command, &args.assets) {
+        if !dir.is_dir() {
+            println!(
+                "art directory {} from --assets does not exist",
+                dir.display()
+            );
+            std::process::exit(2);
+        }
+    }
 
-    let bundle = PokemonBundle {
-        ability: Rc::new(ability),
-        pokemon: pokemon.into_iter().map(Rc::new).collect(),
+    let data = data_dir.and_then(|dir| {
+        let overlays = get_overlay_paths(dir.as_deref(), &args.overlay)?;
+        PokemonData::load(dir.as_deref(), &overlays)
+    });
+
+    let (bundle, ascii) = match data {
+        Ok(data) => {
+            // still readable after leaving the alternate screen
+            for conflict in data.conflicts.iter() {
+                eprintln!("overlay conflict: {}", conflict);
+            }
+            data.bundle()
+        }
+        Err(e) => {
+            println!("load data error: {}", e);
+            std::process::exit(2);
+        }
     };
 
+    let pokemon_list = PokemonListState::new(Rc::new(bundle), AsciiForms::from(ascii))
+        .path(assets.art_dir())
+        .archive(assets.archive.take())
+        .sprites(get_sprite_renderer(args.sprites, args.image_protocol))
+        .locale(args.locale)
+        .alt_names(args.also_show);
+
+    if let Some(Command::Doctor) = args.command {
+        run_doctor(&assets, &pokemon_list);
+    }
+
     // setup terminal
     let mut tui = Tui::init()?;
 
     // create app and run it
-    let app = AppState {
-        pokemon_list: PokemonListState::new(Rc::new(bundle), AsciiForms::from(ascii))
-            .path(assets_dir),
-        tui: TuiState::new(),
+    let mut app = AppState {
+        pokemon_list,
+        tui: TuiState {
+            show_list_column: args.list_column,
+            ..TuiState::new()
+        },
+        quiz: match get_user_data_path("quiz.json") {
+            Some(path) => QuizState::default().path(path),
+            None => QuizState::default(),
+        },
+        trainer: match get_user_data_path("trainer.json") {
+            Some(path) => TrainerState::default().path(path),
+            None => TrainerState::default(),
+        },
         ..Default::default()
     };
 
-    run_app(&mut tui.terminal, app)?;
+    run_app(&mut tui.terminal, &mut app, &mut TerminalEvents)?;
     Ok(())
 }
 
-fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: AppState) -> io::Result<()> {
-    loop {
-        terminal.draw(|f| ui(f, &mut app))?;
+fn parse_locale(code: &str) -> Result<Locale, String> {
+    Locale::parse(code).ok_or_else(|| format!("unsupported locale {}", code))
+}
 
-        if let Event::Key(event) = event::read()? {
-            if handle_key(&mut app, event).is_exit() {
-                return Ok(());
-            }
+fn parse_alt_name(code: &str) -> Result<AltName, String> {
+    AltName::parse(code).ok_or_else(|| format!("unsupported name {}", code))
+}
+
+fn parse_image_protocol(name: &str) -> Result<ImageProtocol, String> {
+    ImageProtocol::parse(name).ok_or_else(|| format!("unsupported image protocol {}", name))
+}
+
+/// sprites of `dir` when the terminal can draw images, with the cell size it reports
+fn get_sprite_renderer(
+    dir: Option<PathBuf>,
+    protocol: Option<ImageProtocol>,
+) -> Option<SpriteRenderer> {
+    let protocol = protocol.or_else(|| ImageProtocol::detect(|var| std::env::var(var).ok()))?;
+    let renderer = SpriteRenderer::new(protocol, dir?);
+
+    // terminals that do not report pixels answer 0, which keeps the default
+    Some(match window_size() {
+        Ok(size) if size.columns > 0 && size.rows > 0 => {
+            renderer.cell_size(size.width / size.columns, size.height / size.rows)
         }
-    }
+        _ => renderer,
+    })
 }
 
-fn load_data() -> Result<(Vec<PokemonEntity>, AbilityMap, AsciiJson), ()> {
-    let pokemon: Vec<PokemonEntity> =
-        from_str(include_str!("../data/data.json")).expect("load pokemon data error");
-    let ability: AbilityMap =
-        from_str(include_str!("../data/ability.json")).expect("load ability data error");
-    let ascii: AsciiJson =
-        from_str(include_str!("../data/ascii.json")).expect("load ascii data error");
-    Ok((pokemon, ability, ascii))
+fn run_pack(dir: &Path, output: &Path) -> ! {
+    match pack(dir).and_then(|archive| std::fs::write(output, archive)) {
+        Ok(()) => {
+            println!("packed {} into {}", dir.display(), output.display());
+            std::process::exit(0);
+        }
+        Err(e) => {
+            println!("pack error: {}", e);
+            std::process::exit(1);
+        }
+    }
 }
 
-fn get_assets_dir_path() -> Result<PathBuf> {
-    let assets_path = Path::new("colorscripts/small");
+fn run_validate(dir: &Path, assets: &Assets) -> ! {
+    let problems = validate(dir, &assets.art_dir(), assets.archive.as_ref());
+    for problem in problems.iter() {
+        println!("{}", problem);
+    }
 
-    // binary execute path
-    if let Ok(execute_path) = std::env::current_exe() {
-        if let Some(execute_dir) = execute_path.parent() {
-            let assets_dir = execute_dir.join(assets_path);
-            if assets_dir.exists() {
-                return Ok(assets_dir);
-            }
-        };
-    };
+    if problems.is_empty() {
+        println!("{} is valid", dir.display());
+        std::process::exit(0);
+    } else {
+        println!("{} problems found", problems.len());
+        std::process::exit(1);
+    }
+}
 
-    // xdg data home
+/// records of the quiz and the trainer, in the user data dir
+fn get_user_data_path(file: &str) -> Option<PathBuf> {
     #[cfg(unix)]
     if let Ok(xdg_dir) = BaseDirectories::new() {
-        let data_home = xdg_dir.get_data_home().join("poketex");
-        let assets_dir = data_home.join(assets_path);
-        if assets_dir.exists() {
-            return Ok(assets_dir);
-        }
-    };
+        return Some(xdg_dir.get_data_home().join("poketex").join(file));
+    }
 
-    // Windows equivalent
     #[cfg(windows)]
     if let Ok(data_dir) = std::env::var("LOCALAPPDATA") {
-        let data_home = Path::new(&data_dir).join("poketex");
-        let assets_dir = data_home.join(assets_path);
-        if assets_dir.exists() {
-            return Ok(assets_dir);
-        }
+        return Some(Path::new(&data_dir).join("poketex").join(file));
+    }
+
+    None
+}
+
+fn run_doctor(assets: &Assets, list: &PokemonListState) -> ! {
+    println!("art directories:");
+    for (dir, probe) in assets.searched_dirs.iter() {
+        println!("  {:<8} {}", probe.to_string(), dir.display());
     }
 
-    let usr_dir = Path::new("/usr/share/poketex");
-    let assets_dir = usr_dir.join(assets_path);
-    if assets_dir.exists() {
-        return Ok(assets_dir);
+    println!("art archives:");
+    for (path, probe) in assets.searched_archives.iter() {
+        println!("  {:<8} {}", probe.to_string(), path.display());
     }
 
-    let usr_dir = Path::new("/usr/local/share/poketex");
-    let assets_dir = usr_dir.join(assets_path);
-    if assets_dir.exists() {
-        return Ok(assets_dir);
+    match &assets.dir {
+        Some(dir) => println!("reading art from {}", dir.display()),
+        None => println!("no art directory found"),
+    }
+    match (&assets.archive_path, list.art_cache.archive.is_some()) {
+        (Some(path), _) => println!("falling back to {}", path.display()),
+        (None, true) => println!("falling back to the archive built into the binary"),
+        (None, false) => println!("no art archive, build with --features embed-art to embed one"),
+    }
+
+    match &list.sprites {
+        Some(sprites) => println!(
+            "drawing sprites of {} with {} images",
+            sprites.dir.display(),
+            sprites.protocol
+        ),
+        None => println!("no sprites, they need --sprites and a terminal that can draw images"),
     }
 
-    // default current dir
-    let current_dir = std::env::current_dir()?;
-    Ok(current_dir.join(assets_path))
+    let missing = list.missing_art();
+    if missing.is_empty() {
+        println!("all art found");
+        std::process::exit(0);
+    }
+
+    for key in missing.iter().take(20) {
+        println!("missing art {}", key.path().display());
+    }
+    if missing.len() > 20 {
+        println!("and {} more", missing.len() - 20);
+    }
+    println!("{} arts missing", missing.len());
+    std::process::exit(1);
 }
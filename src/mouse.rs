@@ -0,0 +1,161 @@
+use ratatui::{
+    crossterm::event::{MouseButton, MouseEvent, MouseEventKind},
+    layout::{Position, Rect},
+};
+
+use crate::state::{
+    pokemon::{MouseState, ScrollTarget, ScrollableParagraphState},
+    tui::Pane,
+    AppState, PokemonListState,
+};
+
+pub fn handle_mouse(app: &mut AppState, event: MouseEvent) {
+    // popups cover the widgets below them
+    if app.tui.show_help || app.compare.is_ready() {
+        return;
+    }
+
+    if app.breeding.is_ready() {
+        match event.kind {
+            MouseEventKind::ScrollDown => app.breeding.scroll.scroll_down(),
+            MouseEventKind::ScrollUp => app.breeding.scroll.scroll_up(),
+            _ => (),
+        }
+        return;
+    }
+
+    // so do the quiz and the trainer
+    if app.has_popup() {
+        return;
+    }
+
+    let position = Position::new(event.column, event.row);
+    let list = &mut app.pokemon_list;
+    let mouse = list.mouse;
+
+    match event.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if let Some(pane) = pane_at(&mouse, position) {
+                app.tui.focus = pane;
+            }
+
+            list.mouse.dragging = scrollbar_at(list, position);
+            if let Some(target) = list.mouse.dragging {
+                drag(list, target, position.y);
+            } else if mouse.list.contains(position) {
+                let index = list.list_state.offset() + (position.y - mouse.list.y) as usize;
+                if index < list.len() && list.list_state.selected() != Some(index) {
+                    list.select(index);
+                }
+            } else if mouse.navigation.contains(position) {
+                // left half of `<- n / m ->` goes back
+                if position.x < mouse.navigation.x + mouse.navigation.width / 2 {
+                    list.previous_profile_page();
+                } else {
+                    list.next_profile_page();
+                }
+            }
+        }
+        MouseEventKind::Drag(MouseButton::Left) => {
+            if let Some(target) = mouse.dragging {
+                drag(list, target, position.y);
+            }
+        }
+        MouseEventKind::Up(MouseButton::Left) => list.mouse.dragging = None,
+        MouseEventKind::ScrollDown => {
+            if mouse.list.contains(position) || mouse.list_scrollbar.contains(position) {
+                list.scroll_down(1);
+            } else if let Some(paragraph) = paragraph_at(list, position) {
+                paragraph.scroll_down();
+            }
+        }
+        MouseEventKind::ScrollUp => {
+            if mouse.list.contains(position) || mouse.list_scrollbar.contains(position) {
+                list.scroll_up(1);
+            } else if let Some(paragraph) = paragraph_at(list, position) {
+                paragraph.scroll_up();
+            }
+        }
+        _ => (),
+    }
+}
+
+/// panes the mouse knows the area of
+fn pane_at(mouse: &MouseState, position: Position) -> Option<Pane> {
+    if mouse.list.contains(position) || mouse.list_scrollbar.contains(position) {
+        Some(Pane::List)
+    } else if mouse.ability.contains(position) {
+        Some(Pane::Abilities)
+    } else if mouse.dex.contains(position) {
+        Some(Pane::Dex)
+    } else {
+        None
+    }
+}
+
+fn scrollbar_at(list: &PokemonListState, position: Position) -> Option<ScrollTarget> {
+    let mouse = &list.mouse;
+
+    if mouse.list_scrollbar.contains(position) {
+        Some(ScrollTarget::List)
+    } else if scrollbar(mouse.ability).contains(position) && list.desc_scrollbar_state.height > 0 {
+        Some(ScrollTarget::Ability)
+    } else if scrollbar(mouse.dex).contains(position) && list.dex_scrollbar_state.height > 0 {
+        Some(ScrollTarget::Dex)
+    } else {
+        None
+    }
+}
+
+fn paragraph_at(
+    list: &mut PokemonListState,
+    position: Position,
+) -> Option<&mut ScrollableParagraphState> {
+    if list.mouse.ability.contains(position) {
+        Some(&mut list.desc_scrollbar_state)
+    } else if list.mouse.dex.contains(position) {
+        Some(&mut list.dex_scrollbar_state)
+    } else {
+        None
+    }
+}
+
+/// scrollbars are drawn on the right column of the area
+fn scrollbar(area: Rect) -> Rect {
+    Rect {
+        x: area.right().saturating_sub(1),
+        width: area.width.min(1),
+        ..area
+    }
+}
+
+/// move the thumb of a scrollbar to the row
+fn drag(list: &mut PokemonListState, target: ScrollTarget, row: u16) {
+    let area = match target {
+        ScrollTarget::List => list.mouse.list_scrollbar,
+        ScrollTarget::Ability => list.mouse.ability,
+        ScrollTarget::Dex => list.mouse.dex,
+    };
+
+    // the first and last rows are the arrows
+    let track = area.height.saturating_sub(3).max(1) as f64;
+    let ratio = (row.saturating_sub(area.y + 1) as f64 / track).min(1.0);
+    let at = |len: usize| (ratio * len.saturating_sub(1) as f64).round() as usize;
+
+    match target {
+        ScrollTarget::List => {
+            let index = at(list.len());
+            if list.list_state.selected() != Some(index) {
+                list.select(index);
+            }
+        }
+        ScrollTarget::Ability => {
+            let height = list.desc_scrollbar_state.height;
+            list.desc_scrollbar_state.scroll_to(at(height));
+        }
+        ScrollTarget::Dex => {
+            let height = list.dex_scrollbar_state.height;
+            list.dex_scrollbar_state.scroll_to(at(height));
+        }
+    }
+}
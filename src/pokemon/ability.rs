@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use super::TranslateText;
+use super::{Locale, TranslateText};
 
 #[derive(Deserialize, Clone, Default)]
 pub struct Ability {
@@ -10,11 +10,11 @@ pub struct Ability {
 }
 
 impl Ability {
-    pub fn name(&self) -> String {
-        self.name.get()
+    pub fn name(&self, locale: Locale) -> String {
+        self.name.get(locale)
     }
 
-    pub fn desc(&self) -> String {
-        self.desc.get()
+    pub fn desc(&self, locale: Locale) -> String {
+        self.desc.get(locale)
     }
 }
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, path::PathBuf};
 
 use serde::Deserialize;
 
@@ -6,43 +6,39 @@ use serde::Deserialize;
 pub struct AsciiJsonItem {
     pub name: String,
     pub forms: Vec<String>,
-}
-
-impl AsciiJsonItem {
-    fn new(name: String, forms: Vec<String>) -> Self {
-        Self { name, forms }
-    }
+    /// custom art directory instead of the colorscripts one
+    #[serde(default)]
+    pub root: Option<PathBuf>,
 }
 
 pub type AsciiJson = Vec<AsciiJsonItem>;
 
 #[derive(Default)]
-pub struct AsciiForms(BTreeMap<String, Vec<String>>);
+pub struct AsciiForms {
+    forms: BTreeMap<String, Vec<String>>,
+    roots: BTreeMap<String, PathBuf>,
+}
 
 impl From<AsciiJson> for AsciiForms {
     fn from(value: AsciiJson) -> Self {
-        Self(
-            value
-                .into_iter()
-                .map(|item| {
-                    AsciiJsonItem::new(
-                        item.name,
-                        item.forms
-                            .into_iter()
-                            .chain(vec![String::from("shiny")])
-                            .collect(),
-                    )
-                })
-                .fold(BTreeMap::new(), |mut map, item| {
-                    map.insert(item.name, item.forms);
-                    map
-                }),
-        )
+        value
+            .into_iter()
+            .fold(AsciiForms::default(), |mut map, item| {
+                if let Some(root) = item.root {
+                    map.roots.insert(item.name.clone(), root);
+                }
+                map.forms.insert(item.name, item.forms);
+                map
+            })
     }
 }
 
 impl AsciiForms {
     pub fn get(&self, name: &str) -> Option<&Vec<String>> {
-        self.0.get(name)
+        self.forms.get(name)
+    }
+
+    pub fn root(&self, name: &str) -> Option<&PathBuf> {
+        self.roots.get(name)
     }
 }
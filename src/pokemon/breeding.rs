@@ -0,0 +1,177 @@
+use std::rc::Rc;
+
+use crate::i18n::tr;
+
+use super::{EggGroup, Locale, PokemonBundle, PokemonEntity};
+
+pub enum BreedingIssue {
+    UnknownEggGroup,
+    Undiscovered,
+    BothDitto,
+    NoSharedEggGroup,
+    Genderless,
+    SameGender,
+    /// the species the egg hatches into is not in the pokedex
+    UnknownOffspring,
+}
+
+impl BreedingIssue {
+    pub fn reason(&self, locale: Locale) -> &'static str {
+        use BreedingIssue::*;
+
+        tr(
+            locale,
+            match self {
+                UnknownEggGroup => "breeding.issue.unknown_egg_group",
+                Undiscovered => "breeding.issue.undiscovered",
+                BothDitto => "breeding.issue.both_ditto",
+                NoSharedEggGroup => "breeding.issue.no_shared_egg_group",
+                Genderless => "breeding.issue.genderless",
+                SameGender => "breeding.issue.same_gender",
+                UnknownOffspring => "breeding.issue.unknown_offspring",
+            },
+        )
+    }
+}
+
+pub enum BreedingResult {
+    Compatible {
+        offspring: Rc<PokemonEntity>,
+        egg_moves: Vec<String>,
+    },
+    Incompatible(BreedingIssue),
+}
+
+impl PokemonBundle {
+    /// the lowest stage of the evolution line
+    pub fn base_form(&self, pm: &PokemonEntity) -> Option<Rc<PokemonEntity>> {
+        let mut base = self.get_by_no(pm.no)?;
+        while let Some(prev) = base.species.evolves_from.and_then(|no| self.get_by_no(no)) {
+            if prev.no == pm.no {
+                break;
+            }
+            base = prev;
+        }
+
+        Some(base)
+    }
+
+    pub fn egg_group_members(&self, group: EggGroup) -> Vec<Rc<PokemonEntity>> {
+        self.pokemon
+            .iter()
+            .filter(|pm| pm.species.egg_group.contains(&group))
+            .cloned()
+            .collect()
+    }
+
+    /// check if `mother` and `father` can breed, the offspring species comes from `mother`
+    /// unless she is a Ditto
+    pub fn check_breeding(&self, mother: &PokemonEntity, father: &PokemonEntity) -> BreedingResult {
+        use BreedingIssue::*;
+        use BreedingResult::*;
+
+        let (m, f) = (&mother.species, &father.species);
+
+        if m.egg_group.is_empty() || f.egg_group.is_empty() {
+            return Incompatible(UnknownEggGroup);
+        }
+
+        if m.is_undiscovered() || f.is_undiscovered() {
+            return Incompatible(Undiscovered);
+        }
+
+        let (parent, partner) = match (m.is_ditto(), f.is_ditto()) {
+            (true, true) => return Incompatible(BothDitto),
+            (true, false) => (father, mother),
+            (false, true) => (mother, father),
+            (false, false) => {
+                if m.is_genderless() || f.is_genderless() {
+                    return Incompatible(Genderless);
+                }
+
+                if !m.egg_group.iter().any(|g| f.egg_group.contains(g)) {
+                    return Incompatible(NoSharedEggGroup);
+                }
+
+                if !m.can_be_female() || !f.can_be_male() {
+                    return Incompatible(SameGender);
+                }
+
+                (mother, father)
+            }
+        };
+
+        let Some(offspring) = self.base_form(parent) else {
+            return Incompatible(UnknownOffspring);
+        };
+
+        // egg moves are passed down from the father, Ditto has none to give
+        // and a father without a known learnset is not assumed to know any
+        let egg_moves = if partner.species.is_ditto() {
+            vec![]
+        } else {
+            let known = &partner.species;
+            offspring
+                .species
+                .egg_moves
+                .iter()
+                .filter(|m| known.learnset.contains(m) || known.egg_moves.contains(m))
+                .cloned()
+                .collect()
+        };
+
+        Compatible {
+            offspring,
+            egg_moves,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(no: u16, gender_rate: i8, egg_moves: &[&str], learnset: &[&str]) -> PokemonEntity {
+        let mut pm = PokemonEntity {
+            no,
+            ..Default::default()
+        };
+        pm.species.egg_group = vec![EggGroup::Field];
+        pm.species.gender_rate = Some(gender_rate);
+        pm.species.egg_moves = egg_moves.iter().map(|m| m.to_string()).collect();
+        pm.species.learnset = learnset.iter().map(|m| m.to_string()).collect();
+        pm
+    }
+
+    fn egg_moves(result: BreedingResult) -> Vec<String> {
+        match result {
+            BreedingResult::Compatible { egg_moves, .. } => egg_moves,
+            BreedingResult::Incompatible(_) => panic!("should be compatible"),
+        }
+    }
+
+    #[test]
+    fn egg_moves_need_a_known_learnset() {
+        let mother = entity(1, 4, &["Wish", "Curse"], &[]);
+        let bundle = PokemonBundle {
+            pokemon: vec![Rc::new(mother.clone())],
+            ..Default::default()
+        };
+
+        let father = entity(2, 4, &[], &[]);
+        assert!(egg_moves(bundle.check_breeding(&mother, &father)).is_empty());
+
+        let father = entity(2, 4, &[], &["Curse"]);
+        assert_eq!(
+            egg_moves(bundle.check_breeding(&mother, &father)),
+            ["Curse"]
+        );
+
+        // a mother missing from the pokedex has no offspring to hatch
+        let stray = entity(3, 4, &[], &[]);
+        assert!(matches!(
+            bundle.check_breeding(&stray, &father),
+            BreedingResult::Incompatible(BreedingIssue::UnknownOffspring)
+        ));
+    }
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use super::{translate::translate_game_version, Locale, TranslateText};
+
+#[derive(Deserialize, Clone, Default)]
+pub struct DexEntry {
+    pub version: String,
+    text: TranslateText,
+}
+
+impl DexEntry {
+    pub fn version_name(&self, locale: Locale) -> String {
+        let name = translate_game_version(&self.version).get(locale);
+        if name.is_empty() {
+            self.version.clone()
+        } else {
+            name
+        }
+    }
+
+    pub fn text(&self, locale: Locale) -> String {
+        self.text.get(locale)
+    }
+}
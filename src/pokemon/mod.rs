@@ -1,41 +1,111 @@
 pub mod ability;
 pub mod ascii_form;
+mod breeding;
+pub mod dex;
 #[allow(clippy::module_inception)]
 pub mod pokemon;
+mod query;
+pub mod regional;
+mod romaji;
+mod species;
 mod translate;
+mod type_chart;
 
 use std::{collections::HashMap, rc::Rc};
 
 use ability::*;
+pub use breeding::*;
+use dex::*;
 pub use pokemon::*;
+pub use query::*;
+use regional::*;
+pub use romaji::*;
+pub use species::*;
 pub use translate::*;
+pub use type_chart::*;
 
 pub type AbilityMap = HashMap<u16, Ability>;
+pub type DexEntryMap = HashMap<u16, Vec<DexEntry>>;
+pub type RegionalDexList = Vec<RegionalDex>;
 
 #[derive(Default)]
 pub struct PokemonBundle {
     pub pokemon: Vec<Rc<PokemonEntity>>,
     pub ability: Rc<AbilityMap>,
+    pub dex: Rc<DexEntryMap>,
+    pub regional: Rc<RegionalDexList>,
 }
 
 impl PokemonBundle {
-    fn get_ability(&self, id: u16) -> Option<PokemonAbilityText> {
+    pub fn get_by_no(&self, no: u16) -> Option<Rc<PokemonEntity>> {
+        self.pokemon
+            .get((no as usize).saturating_sub(1))
+            .filter(|pm| pm.no == no)
+            .or_else(|| self.pokemon.iter().find(|pm| pm.no == no))
+            .cloned()
+    }
+
+    /// find by name in any language, exact match first and then prefix match
+    pub fn find_by_name(&self, name: &str) -> Option<Rc<PokemonEntity>> {
+        let name = name.trim().to_lowercase();
+        let names = |pm: &PokemonEntity| {
+            pm.name
+                .values()
+                .map(|n| n.to_lowercase())
+                .collect::<Vec<_>>()
+        };
+
+        self.pokemon
+            .iter()
+            .find(|pm| names(pm).contains(&name))
+            .or_else(|| {
+                self.pokemon
+                    .iter()
+                    .find(|pm| names(pm).iter().any(|n| n.starts_with(&name)))
+            })
+            .cloned()
+    }
+
+    fn get_ability(&self, id: u16, locale: Locale) -> Option<PokemonAbilityText> {
         let ability = self.ability.get(&id)?;
         Some(PokemonAbilityText {
-            name: ability.name().to_string(),
-            desc: ability.desc().to_string(),
+            name: ability.name(locale),
+            desc: ability.desc(locale),
         })
     }
 
-    pub fn get_ability_text(&self, pm: &PokemonEntity) -> Vec<PokemonAbilityText> {
+    pub fn get_ability_text(&self, pm: &PokemonEntity, locale: Locale) -> Vec<PokemonAbilityText> {
         pm.ability()
             .iter()
-            .filter_map(|id| self.get_ability(*id))
+            .filter_map(|id| self.get_ability(*id, locale))
             .collect::<Vec<_>>()
     }
+
+    pub fn has_dex_text(&self, pm: &PokemonEntity) -> bool {
+        self.dex
+            .get(&pm.no)
+            .is_some_and(|entries| !entries.is_empty())
+    }
+
+    pub fn get_dex_text(&self, pm: &PokemonEntity, locale: Locale) -> Vec<PokemonDexText> {
+        self.dex.get(&pm.no).map_or_else(Vec::new, |entries| {
+            entries
+                .iter()
+                .map(|entry| PokemonDexText {
+                    version: entry.version_name(locale),
+                    text: entry.text(locale),
+                })
+                .collect()
+        })
+    }
 }
 
 pub struct PokemonAbilityText {
     pub name: String,
     pub desc: String,
 }
+
+pub struct PokemonDexText {
+    pub version: String,
+    pub text: String,
+}
@@ -3,11 +3,14 @@ use std::fmt::{self, Display};
 use ratatui::style::Color;
 use serde::{
     de::{self, Visitor},
-    Deserialize, Deserializer,
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
 };
 use serde_json::Value;
 
-use super::{TranslateRegionForm, TranslateText};
+use crate::i18n::tr;
+
+use super::{Locale, PokemonSpecies, TranslateRegionForm, TranslateText};
 
 pub type PokemonAbility = Vec<u16>;
 pub type PokemonRegionForm = Vec<PokemonRegionFormEntity>;
@@ -15,7 +18,7 @@ pub type PokemonRegionForm = Vec<PokemonRegionFormEntity>;
 pub trait Pokemon {
     fn no(&self) -> u16;
     fn r#type(&self) -> PokemonType;
-    fn name(&self) -> String;
+    fn name(&self, locale: Locale) -> String;
     fn iv(&self) -> PokemonIV;
     fn ability(&self) -> PokemonAbility;
 }
@@ -38,6 +41,8 @@ pub struct PokemonEntity {
     pub iv: PokemonIV,
     pub form: Option<PokemonRegionForm>,
     pub ability: PokemonAbility,
+    #[serde(flatten)]
+    pub species: PokemonSpecies,
 }
 
 impl Pokemon for PokemonEntity {
@@ -52,8 +57,8 @@ impl Pokemon for PokemonEntity {
     }
 
     #[inline]
-    fn name(&self) -> String {
-        self.name.get().to_string()
+    fn name(&self, locale: Locale) -> String {
+        self.name.get(locale)
     }
 
     #[inline]
@@ -72,8 +77,12 @@ impl PokemonEntity {
         format!("#{} {}", no.as_ref(), name.as_ref())
     }
 
-    pub fn name_with_no(&self) -> String {
-        PokemonEntity::name_of_list(self.no.to_string(), self.name.get().to_string())
+    pub fn name_with_no(&self, locale: Locale) -> String {
+        self.name_with_dex_no(self.no, locale)
+    }
+
+    pub fn name_with_dex_no(&self, no: u16, locale: Locale) -> String {
+        PokemonEntity::name_of_list(no.to_string(), self.name.get(locale))
     }
 
     pub fn region_form(&self) -> Option<Vec<PokemonEntity>> {
@@ -87,6 +96,7 @@ impl PokemonEntity {
                 name: TranslateRegionForm::new(self.name.clone(), f.form.clone()).into(),
                 ability: f.ability.clone(),
                 iv: f.iv,
+                species: self.species.clone(),
                 ..Default::default()
             })
             .collect::<Vec<_>>();
@@ -95,7 +105,29 @@ impl PokemonEntity {
     }
 
     pub fn default_name(&self) -> String {
-        self.name.en.clone()
+        self.name.en().to_string()
+    }
+
+    /// file name of the colorscript, without form suffix
+    pub fn ascii_name(&self) -> String {
+        self.default_name()
+            .replace("Galarian form", "galar")
+            .replace("Alola Form", "alola")
+            .replace("Hisuian form", "hisui")
+            .replace("Paldea form", "paldea")
+            .replace(" - ", "-")
+            .replace(' ', "-")
+            .replace('♀', "-f")
+            .replace('♂', "-m")
+            .to_lowercase()
+    }
+
+    pub fn generation(&self) -> u8 {
+        if self.species.generation > 0 {
+            self.species.generation
+        } else {
+            PokemonSpecies::generation_of(self.no)
+        }
     }
 }
 
@@ -107,7 +139,7 @@ pub struct PokemonRegionFormEntity {
     pub form: Vec<String>,
 }
 
-#[derive(Debug, PartialEq, Default, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Copy)]
 pub enum PokemonTypeKind {
     Fire,
     Grass,
@@ -140,7 +172,68 @@ impl<'de> Deserialize<'de> for PokemonTypeKind {
     }
 }
 
+/// lowercase english name, as in the data files
+impl Serialize for PokemonTypeKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string().to_lowercase())
+    }
+}
+
 impl PokemonTypeKind {
+    pub const ALL: [PokemonTypeKind; 18] = [
+        PokemonTypeKind::Normal,
+        PokemonTypeKind::Fire,
+        PokemonTypeKind::Water,
+        PokemonTypeKind::Electric,
+        PokemonTypeKind::Grass,
+        PokemonTypeKind::Ice,
+        PokemonTypeKind::Fighting,
+        PokemonTypeKind::Poison,
+        PokemonTypeKind::Ground,
+        PokemonTypeKind::Flying,
+        PokemonTypeKind::Psychic,
+        PokemonTypeKind::Bug,
+        PokemonTypeKind::Rock,
+        PokemonTypeKind::Ghost,
+        PokemonTypeKind::Dragon,
+        PokemonTypeKind::Dark,
+        PokemonTypeKind::Steel,
+        PokemonTypeKind::Fairy,
+    ];
+
+    /// translated name, `Display` is the english one
+    pub fn name(&self, locale: Locale) -> &'static str {
+        use PokemonTypeKind::*;
+
+        tr(
+            locale,
+            match self {
+                Fire => "type.fire",
+                Grass => "type.grass",
+                Water => "type.water",
+                Normal => "type.normal",
+                Electric => "type.electric",
+                Ice => "type.ice",
+                Fighting => "type.fighting",
+                Poison => "type.poison",
+                Ground => "type.ground",
+                Flying => "type.flying",
+                Psychic => "type.psychic",
+                Bug => "type.bug",
+                Rock => "type.rock",
+                Ghost => "type.ghost",
+                Dragon => "type.dragon",
+                Dark => "type.dark",
+                Steel => "type.steel",
+                Fairy => "type.fairy",
+                Other => "type.other",
+            },
+        )
+    }
+
     pub fn color(&self) -> Color {
         use PokemonTypeKind::*;
 
@@ -202,7 +295,7 @@ impl<'de> Visitor<'de> for PokemonTypeKindVisitor {
     type Value = PokemonTypeKind;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("Pokemon Type Kind")
+        formatter.write_str("a lowercase type name like \"fire\"")
     }
 
     fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
@@ -230,14 +323,29 @@ impl<'de> Visitor<'de> for PokemonTypeKindVisitor {
             "dark" => Ok(Dark),
             "steel" => Ok(Steel),
             "fairy" => Ok(Fairy),
-            _ => Ok(Other),
+            "other" => Ok(Other),
+            _ => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
         }
     }
 }
 
-#[derive(Default, Clone, Copy)]
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
 pub struct PokemonType(pub PokemonTypeKind, pub Option<PokemonTypeKind>);
 
+impl Serialize for PokemonType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element(&self.0)?;
+        if let Some(second) = &self.1 {
+            seq.serialize_element(second)?;
+        }
+        seq.end()
+    }
+}
+
 impl<'de> Deserialize<'de> for PokemonType {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -248,12 +356,15 @@ impl<'de> Deserialize<'de> for PokemonType {
         if let Some(array) = v.as_array() {
             match array.len() {
                 1 => {
-                    let first = serde_json::from_value(array[0].clone()).expect("wrong type");
+                    let first =
+                        serde_json::from_value(array[0].clone()).map_err(de::Error::custom)?;
                     Ok(PokemonType(first, None))
                 }
                 2 => {
-                    let first = serde_json::from_value(array[0].clone()).expect("wrong type");
-                    let second = serde_json::from_value(array[1].clone()).expect("wrong type");
+                    let first =
+                        serde_json::from_value(array[0].clone()).map_err(de::Error::custom)?;
+                    let second =
+                        serde_json::from_value(array[1].clone()).map_err(de::Error::custom)?;
                     Ok(PokemonType(first, Some(second)))
                 }
                 _ => Err(serde::de::Error::invalid_length(
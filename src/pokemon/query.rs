@@ -0,0 +1,162 @@
+use std::{cmp::Reverse, rc::Rc};
+
+use super::{Locale, PokemonEntity, PokemonIV};
+
+enum QueryTerm {
+    Type(String),
+    Generation(u8),
+    EggGroup(String),
+    GrowthRate(String),
+    Category(String),
+}
+
+/// search query of pokemon list
+///
+/// plain words are matched against `#no name` in any language, and `key:value` words filter by
+/// species data,
+/// e.g. `gen:1 egg:monster saur`
+#[derive(Default)]
+pub struct PokemonQuery {
+    keyword: String,
+    terms: Vec<QueryTerm>,
+}
+
+impl PokemonQuery {
+    pub fn parse(query: &str) -> Self {
+        let mut keyword = vec![];
+        let mut terms = vec![];
+
+        for word in query.split_whitespace() {
+            let term = word.split_once(':').and_then(|(key, value)| {
+                let value = normalize(value);
+                match key.to_lowercase().as_str() {
+                    "type" | "t" => Some(QueryTerm::Type(value)),
+                    "gen" | "g" => value.parse().ok().map(QueryTerm::Generation),
+                    "egg" => Some(QueryTerm::EggGroup(value)),
+                    "growth" => Some(QueryTerm::GrowthRate(value)),
+                    "category" | "cat" => Some(QueryTerm::Category(value)),
+                    _ => None,
+                }
+            });
+
+            match term {
+                Some(term) => terms.push(term),
+                None => keyword.push(word),
+            }
+        }
+
+        Self {
+            keyword: keyword.join(" ").to_lowercase(),
+            terms,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keyword.is_empty() && self.terms.is_empty()
+    }
+
+    /// `no` is the number shown in the list, which differs from national number in regional dex
+    pub fn matches(&self, pm: &PokemonEntity, no: u16) -> bool {
+        (self.keyword.is_empty()
+            || pm.name.values().any(|name| {
+                format!("#{} {}", no, name)
+                    .to_lowercase()
+                    .contains(&self.keyword)
+            }))
+            && self.terms.iter().all(|term| Self::match_term(term, pm))
+    }
+
+    fn match_term(term: &QueryTerm, pm: &PokemonEntity) -> bool {
+        let species = &pm.species;
+
+        match term {
+            QueryTerm::Type(t) => {
+                normalize(pm.r#type.0.to_string()) == *t
+                    || pm.r#type.1.is_some_and(|k| normalize(k.to_string()) == *t)
+            }
+            QueryTerm::Generation(g) => pm.generation() == *g,
+            QueryTerm::EggGroup(g) => species
+                .egg_group
+                .iter()
+                .any(|e| normalize(e.to_string()) == *g),
+            QueryTerm::GrowthRate(r) => species
+                .growth_rate
+                .is_some_and(|e| normalize(e.to_string()) == *r),
+            QueryTerm::Category(c) => species
+                .category
+                .as_ref()
+                .is_some_and(|e| e.values().any(|t| normalize(t).contains(c.as_str()))),
+        }
+    }
+}
+
+/// order of pokemon list, stats are sorted from high to low
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortKey {
+    #[default]
+    No,
+    Name,
+    Hp,
+    Atk,
+    Def,
+    SAtk,
+    SDef,
+    Spd,
+    Total,
+}
+
+impl SortKey {
+    pub const NAMES: [&'static str; 9] = [
+        "no", "name", "hp", "atk", "def", "satk", "sdef", "spd", "total",
+    ];
+
+    pub fn parse(key: &str) -> Option<Self> {
+        use SortKey::*;
+
+        match normalize(key).as_str() {
+            "no" | "dex" => Some(No),
+            "name" => Some(Name),
+            "hp" => Some(Hp),
+            "atk" | "att" => Some(Atk),
+            "def" => Some(Def),
+            "satk" | "satt" | "spa" => Some(SAtk),
+            "sdef" | "spdef" => Some(SDef),
+            "spd" | "speed" | "spe" => Some(Spd),
+            "total" | "bst" => Some(Total),
+            _ => None,
+        }
+    }
+
+    fn stat(self, iv: &PokemonIV) -> u16 {
+        use SortKey::*;
+
+        match self {
+            Hp => iv.hp,
+            Atk => iv.att,
+            Def => iv.def,
+            SAtk => iv.s_att,
+            SDef => iv.s_def,
+            Spd => iv.spd,
+            Total => iv.hp + iv.att + iv.def + iv.s_att + iv.s_def + iv.spd,
+            No | Name => 0,
+        }
+    }
+
+    /// stable sort, so pokemon with the same value keep dex order
+    pub fn sort(self, list: &mut [Rc<PokemonEntity>], locale: Locale) {
+        match self {
+            SortKey::No => (),
+            SortKey::Name => list.sort_by_cached_key(|pm| pm.name.get(locale).to_lowercase()),
+            key => list.sort_by_key(|pm| Reverse(key.stat(&pm.iv))),
+        }
+    }
+}
+
+fn normalize<T: AsRef<str>>(value: T) -> String {
+    value
+        .as_ref()
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
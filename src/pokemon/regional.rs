@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+use super::{
+    translate::{translate_game_version, translate_regional_dex},
+    Locale, TranslateText,
+};
+
+#[derive(Deserialize, Clone, Default)]
+pub struct RegionalDex {
+    pub dex: String,
+    /// games using this dex
+    pub games: Vec<String>,
+    /// national numbers in regional order
+    pub pokemon: Vec<u16>,
+}
+
+impl RegionalDex {
+    pub fn name(&self, locale: Locale) -> String {
+        Self::translate_or_key(translate_regional_dex(&self.dex), &self.dex, locale)
+    }
+
+    pub fn games_name(&self, locale: Locale) -> String {
+        self.games
+            .iter()
+            .map(|g| Self::translate_or_key(translate_game_version(g), g, locale))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn translate_or_key(text: TranslateText, key: &str, locale: Locale) -> String {
+        let text = text.get(locale);
+        if text.is_empty() {
+            key.to_string()
+        } else {
+            text
+        }
+    }
+}
@@ -0,0 +1,211 @@
+/// transliterate japanese kana to romaji
+///
+/// hepburn syllables written as typed on a keyboard, long vowels are doubled instead of using
+/// macrons, e.g. `リザードン` is `Rizaadon`; other characters are kept as they are
+pub fn romaji(text: &str) -> String {
+    let mut syllables: Vec<String> = vec![];
+    let mut sokuon = false;
+
+    for c in text.chars().map(normalize) {
+        if let Some(syllable) = kana(c) {
+            let mut syllable = syllable.to_string();
+            if std::mem::take(&mut sokuon) {
+                // small tsu doubles the next consonant, `ch` becomes `tch`
+                match syllable.chars().next() {
+                    Some('c') => syllable.insert(0, 't'),
+                    Some(first) if !is_vowel(first) && first != 'n' => syllable.insert(0, first),
+                    _ => (),
+                }
+            }
+            syllables.push(syllable);
+            continue;
+        }
+
+        sokuon = false;
+        match (c, syllables.last_mut()) {
+            ('ッ', _) => sokuon = true,
+            ('ー', Some(last)) => {
+                if let Some(vowel) = last.chars().last().filter(|c| is_vowel(*c)) {
+                    last.push(vowel);
+                }
+            }
+            ('ャ' | 'ュ' | 'ョ', Some(last)) if last.ends_with('i') && last.len() > 1 => {
+                last.pop();
+                if !(last.ends_with("sh") || last.ends_with("ch") || last.ends_with('j')) {
+                    last.push('y');
+                }
+                last.push_str(small_vowel(c));
+            }
+            ('ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ', Some(last)) if last == "u" => {
+                *last = format!("w{}", small_vowel(c));
+            }
+            ('ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ', Some(last))
+                if last.len() > 1 && last.chars().last().is_some_and(is_vowel) =>
+            {
+                last.pop();
+                last.push_str(small_vowel(c));
+            }
+            ('ャ' | 'ュ' | 'ョ' | 'ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ', _) => {
+                syllables.push(small_vowel(c).to_string())
+            }
+            ('・', _) => syllables.push(String::from(" ")),
+            (c, _) => syllables.push(c.to_string()),
+        }
+    }
+
+    let mut text = String::new();
+    let mut word_start = true;
+    for (i, syllable) in syllables.iter().enumerate() {
+        text.push_str(syllable);
+
+        // `n'` before a vowel or `y`, so `ンア` is not read as `ナ`
+        let next = syllables.get(i + 1).and_then(|s| s.chars().next());
+        if syllable == "n" && next.is_some_and(|c| is_vowel(c) || c == 'y') {
+            text.push('\'');
+        }
+    }
+
+    text.chars()
+        .map(|c| {
+            let c = if word_start {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            };
+            word_start = !c.is_alphabetic() && c != '\'';
+            c
+        })
+        .collect()
+}
+
+/// hiragana as katakana, fullwidth ascii as ascii
+fn normalize(c: char) -> char {
+    match c {
+        'ぁ'..='ゖ' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+        '！'..='～' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        _ => c,
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+fn small_vowel(c: char) -> &'static str {
+    match c {
+        'ァ' => "a",
+        'ィ' => "i",
+        'ゥ' | 'ュ' => "u",
+        'ェ' => "e",
+        'ォ' | 'ョ' => "o",
+        'ャ' => "a",
+        _ => "",
+    }
+}
+
+fn kana(c: char) -> Option<&'static str> {
+    let syllable = match c {
+        'ア' => "a",
+        'イ' => "i",
+        'ウ' => "u",
+        'エ' => "e",
+        'オ' => "o",
+        'カ' => "ka",
+        'キ' => "ki",
+        'ク' => "ku",
+        'ケ' => "ke",
+        'コ' => "ko",
+        'ガ' => "ga",
+        'ギ' => "gi",
+        'グ' => "gu",
+        'ゲ' => "ge",
+        'ゴ' => "go",
+        'サ' => "sa",
+        'シ' => "shi",
+        'ス' => "su",
+        'セ' => "se",
+        'ソ' => "so",
+        'ザ' => "za",
+        'ジ' | 'ヂ' => "ji",
+        'ズ' | 'ヅ' => "zu",
+        'ゼ' => "ze",
+        'ゾ' => "zo",
+        'タ' => "ta",
+        'チ' => "chi",
+        'ツ' => "tsu",
+        'テ' => "te",
+        'ト' => "to",
+        'ダ' => "da",
+        'デ' => "de",
+        'ド' => "do",
+        'ナ' => "na",
+        'ニ' => "ni",
+        'ヌ' => "nu",
+        'ネ' => "ne",
+        'ノ' => "no",
+        'ハ' => "ha",
+        'ヒ' => "hi",
+        'フ' => "fu",
+        'ヘ' => "he",
+        'ホ' => "ho",
+        'バ' => "ba",
+        'ビ' => "bi",
+        'ブ' => "bu",
+        'ベ' => "be",
+        'ボ' => "bo",
+        'パ' => "pa",
+        'ピ' => "pi",
+        'プ' => "pu",
+        'ペ' => "pe",
+        'ポ' => "po",
+        'マ' => "ma",
+        'ミ' => "mi",
+        'ム' => "mu",
+        'メ' => "me",
+        'モ' => "mo",
+        'ヤ' => "ya",
+        'ユ' => "yu",
+        'ヨ' => "yo",
+        'ラ' => "ra",
+        'リ' => "ri",
+        'ル' => "ru",
+        'レ' => "re",
+        'ロ' => "ro",
+        'ワ' => "wa",
+        'ヲ' => "o",
+        'ン' => "n",
+        'ヴ' => "vu",
+        _ => return None,
+    };
+
+    Some(syllable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kana_to_romaji() {
+        let cases = [
+            ("フシギダネ", "Fushigidane"),
+            ("リザードン", "Rizaadon"),
+            ("ピカチュウ", "Pikachuu"),
+            ("ニャース", "Nyaasu"),
+            ("ミュウツー", "Myuutsuu"),
+            ("ポッチャマ", "Potchama"),
+            ("シェイミ", "Sheimi"),
+            ("ウォーグル", "Wooguru"),
+            ("ファイアロー", "Faiaroo"),
+            ("カプ・コケコ", "Kapu Kokeko"),
+            ("ポリゴン２", "Porigon2"),
+            ("タイプ：ヌル", "Taipu:Nuru"),
+            ("ゲンガー", "Gengaa"),
+            ("ライチュウ - アローラのすがた", "Raichuu - Arooranosugata"),
+        ];
+
+        for (kana, expected) in cases {
+            assert_eq!(romaji(kana), expected);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::{self, Display};
+
+use serde::Deserialize;
+
+use super::TranslateText;
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PokemonSpecies {
+    /// height in meters
+    pub height: Option<f32>,
+    /// weight in kilograms
+    pub weight: Option<f32>,
+    pub category: Option<TranslateText>,
+    pub generation: u8,
+    pub egg_group: Vec<EggGroup>,
+    pub catch_rate: Option<u8>,
+    pub base_exp: Option<u16>,
+    pub growth_rate: Option<GrowthRate>,
+    pub friendship: Option<u8>,
+    /// female ratio in eighths, -1 for genderless
+    pub gender_rate: Option<i8>,
+    pub evolves_from: Option<u16>,
+    pub egg_moves: Vec<String>,
+    pub learnset: Vec<String>,
+}
+
+impl PokemonSpecies {
+    pub fn generation_of(no: u16) -> u8 {
+        match no {
+            0 => 0,
+            1..=151 => 1,
+            152..=251 => 2,
+            252..=386 => 3,
+            387..=493 => 4,
+            494..=649 => 5,
+            650..=721 => 6,
+            722..=809 => 7,
+            810..=905 => 8,
+            _ => 9,
+        }
+    }
+
+    pub fn is_ditto(&self) -> bool {
+        self.egg_group.contains(&EggGroup::Ditto)
+    }
+
+    pub fn is_undiscovered(&self) -> bool {
+        self.egg_group.contains(&EggGroup::Undiscovered)
+    }
+
+    pub fn is_genderless(&self) -> bool {
+        self.gender_rate.is_some_and(|r| r < 0)
+    }
+
+    pub fn can_be_female(&self) -> bool {
+        self.gender_rate.is_none_or(|r| r > 0)
+    }
+
+    pub fn can_be_male(&self) -> bool {
+        self.gender_rate.is_none_or(|r| (0..8).contains(&r))
+    }
+
+    pub fn egg_group_text(&self) -> String {
+        self.egg_group
+            .iter()
+            .map(|g| g.to_string())
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum EggGroup {
+    Monster,
+    Water1,
+    Bug,
+    Flying,
+    Field,
+    Fairy,
+    Grass,
+    HumanLike,
+    Water3,
+    Mineral,
+    Amorphous,
+    Water2,
+    Ditto,
+    Dragon,
+    Undiscovered,
+}
+
+impl Display for EggGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use EggGroup::*;
+
+        match self {
+            Monster => write!(f, "Monster"),
+            Water1 => write!(f, "Water 1"),
+            Bug => write!(f, "Bug"),
+            Flying => write!(f, "Flying"),
+            Field => write!(f, "Field"),
+            Fairy => write!(f, "Fairy"),
+            Grass => write!(f, "Grass"),
+            HumanLike => write!(f, "Human-Like"),
+            Water3 => write!(f, "Water 3"),
+            Mineral => write!(f, "Mineral"),
+            Amorphous => write!(f, "Amorphous"),
+            Water2 => write!(f, "Water 2"),
+            Ditto => write!(f, "Ditto"),
+            Dragon => write!(f, "Dragon"),
+            Undiscovered => write!(f, "Undiscovered"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum GrowthRate {
+    Erratic,
+    Fast,
+    MediumFast,
+    MediumSlow,
+    Slow,
+    Fluctuating,
+}
+
+impl Display for GrowthRate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GrowthRate::*;
+
+        match self {
+            Erratic => write!(f, "Erratic"),
+            Fast => write!(f, "Fast"),
+            MediumFast => write!(f, "Medium Fast"),
+            MediumSlow => write!(f, "Medium Slow"),
+            Slow => write!(f, "Slow"),
+            Fluctuating => write!(f, "Fluctuating"),
+        }
+    }
+}
@@ -1,37 +1,200 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
 use serde::Deserialize;
 
-use crate::env::{DEF_LOCALES, LOCALES};
+use super::romaji;
 
-#[derive(Deserialize, Clone, Default)]
-pub struct TranslateText {
-    pub zh: String,
-    pub en: String,
-    pub jp: String,
+/// languages of the ui and data
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    Ja,
+    /// traditional chinese, which the bundled data uses
+    Zh,
+    ZhHans,
+    Ko,
+    Fr,
+    De,
+    Es,
+    It,
 }
 
-impl TranslateText {
-    pub fn get(&self) -> String {
-        unsafe {
-            let loc = if !DEF_LOCALES.eq(LOCALES.as_str()) {
-                DEF_LOCALES
-            } else {
-                LOCALES.as_str()
-            };
-
-            let text = match loc {
-                "en" => &self.en,
-                "zh" => &self.zh,
-                "ja" => &self.jp,
-                _ => &self.en,
-            };
-
-            if !text.is_empty() {
-                text.to_string()
-            } else {
-                self.en.clone()
+impl Locale {
+    pub const ALL: [Locale; 9] = [
+        Locale::En,
+        Locale::Ja,
+        Locale::Zh,
+        Locale::ZhHans,
+        Locale::Ko,
+        Locale::Fr,
+        Locale::De,
+        Locale::Es,
+        Locale::It,
+    ];
+
+    /// code used as key of translations
+    pub fn code(self) -> &'static str {
+        use Locale::*;
+
+        match self {
+            En => "en",
+            Ja => "ja",
+            Zh => "zh",
+            ZhHans => "zh-Hans",
+            Ko => "ko",
+            Fr => "fr",
+            De => "de",
+            Es => "es",
+            It => "it",
+        }
+    }
+
+    /// parse a language code or a system locale like `zh-CN` or `fr_FR`
+    pub fn parse(code: &str) -> Option<Self> {
+        let code = code.replace('_', "-").to_lowercase();
+
+        if let Some(locale) = Self::ALL
+            .into_iter()
+            .find(|l| l.code().to_lowercase() == code)
+        {
+            return Some(locale);
+        }
+
+        match code.as_str() {
+            "jp" => Some(Locale::Ja),
+            "zh-cn" | "zh-sg" => Some(Locale::ZhHans),
+            c if c.starts_with("zh-hans") => Some(Locale::ZhHans),
+            c => {
+                let lang = c.split('-').next()?;
+                Self::ALL.into_iter().find(|l| l.code() == lang)
             }
         }
     }
+
+    /// locale tried before english when a text is missing
+    fn fallback(self) -> Option<Locale> {
+        match self {
+            Locale::ZhHans => Some(Locale::Zh),
+            _ => None,
+        }
+    }
+
+    /// the locale and the ones tried after it, ending with english
+    pub fn fallbacks(self) -> impl Iterator<Item = Locale> {
+        let mut fallbacks = vec![self];
+        while let Some(next) = fallbacks.last().and_then(|l| l.fallback()) {
+            fallbacks.push(next);
+        }
+        if self != Locale::En {
+            fallbacks.push(Locale::En);
+        }
+
+        fallbacks.into_iter()
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|l| *l == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// a name shown besides the one of the ui locale, `romaji` transliterates the japanese name
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AltName {
+    Locale(Locale),
+    Romaji,
+}
+
+impl AltName {
+    pub fn parse(code: &str) -> Option<Self> {
+        match code.to_lowercase().as_str() {
+            "romaji" | "ja-latn" => Some(AltName::Romaji),
+            code => Locale::parse(code).map(AltName::Locale),
+        }
+    }
+
+    /// text without fallback, a missing translation is not shown as the english one
+    pub fn text(self, text: &TranslateText) -> Option<String> {
+        match self {
+            AltName::Locale(locale) => text.text(locale.code()).map(str::to_string),
+            AltName::Romaji => text.text("ja").map(romaji),
+        }
+    }
+}
+
+impl Display for AltName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AltName::Locale(locale) => write!(f, "{}", locale),
+            AltName::Romaji => write!(f, "romaji"),
+        }
+    }
+}
+
+/// text keyed by language code, e.g. `{"en": "Pikachu", "ja": "ピカチュウ"}`
+///
+/// `jp` is accepted as the code of japanese, empty texts count as missing
+#[derive(Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(from = "BTreeMap<String, String>")]
+pub struct TranslateText(BTreeMap<String, String>);
+
+impl From<BTreeMap<String, String>> for TranslateText {
+    fn from(map: BTreeMap<String, String>) -> Self {
+        TranslateText(
+            map.into_iter()
+                .filter(|(_, text)| !text.is_empty())
+                .map(|(code, text)| match code.as_str() {
+                    "jp" => (String::from("ja"), text),
+                    _ => (code, text),
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<const N: usize> From<[(&str, &str); N]> for TranslateText {
+    fn from(texts: [(&str, &str); N]) -> Self {
+        texts
+            .into_iter()
+            .map(|(code, text)| (code.to_string(), text.to_string()))
+            .collect::<BTreeMap<_, _>>()
+            .into()
+    }
+}
+
+impl TranslateText {
+    /// text of the locale, falling back to english
+    pub fn get(&self, locale: Locale) -> String {
+        locale
+            .fallbacks()
+            .find_map(|l| self.text(l.code()))
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    /// text of a language code without fallback
+    pub fn text(&self, code: &str) -> Option<&str> {
+        self.0.get(code).map(String::as_str)
+    }
+
+    pub fn en(&self) -> &str {
+        self.text("en").unwrap_or_default()
+    }
+
+    /// texts of all languages
+    pub fn values(&self) -> impl Iterator<Item = &str> {
+        self.0.values().map(String::as_str)
+    }
 }
 
 pub struct TranslateRegionForm {
@@ -45,99 +208,196 @@ impl TranslateRegionForm {
     }
 }
 
+/// `name - form` in every language of the name, forms fall back to english
 impl From<TranslateRegionForm> for TranslateText {
     fn from(t: TranslateRegionForm) -> Self {
-        let forms = t.form.iter().map(translate_region_form);
-
-        TranslateText {
-            zh: format!(
-                "{}{}",
-                &t.name.zh,
-                &forms.clone().fold(String::new(), |mut acc, t| {
-                    acc.push_str(" - ");
-                    acc.push_str(&t.zh);
-                    acc
-                })
-            ),
-            en: format!(
-                "{}{}",
-                &t.name.en,
-                &forms.clone().fold(String::new(), |mut acc, t| {
-                    acc.push_str(" - ");
-                    acc.push_str(&t.en);
-                    acc
-                })
-            ),
-            jp: format!(
-                "{}{}",
-                &t.name.jp,
-                &forms.fold(String::new(), |mut acc, t| {
-                    acc.push_str(" - ");
-                    acc.push_str(&t.jp);
-                    acc
+        let forms = t.form.iter().map(translate_region_form).collect::<Vec<_>>();
+
+        TranslateText(
+            t.name
+                .0
+                .into_iter()
+                .map(|(code, name)| {
+                    let text = forms.iter().fold(name, |mut acc, form| {
+                        acc.push_str(" - ");
+                        acc.push_str(form.text(&code).unwrap_or(form.en()));
+                        acc
+                    });
+                    (code, text)
                 })
-            ),
-        }
+                .collect(),
+        )
     }
 }
 
 fn translate_region_form<T: AsRef<str>>(form: T) -> TranslateText {
     match form.as_ref() {
-        "Alola" => TranslateText {
-            zh: "阿羅拉的樣子".to_string(),
-            en: "Alola Form".to_string(),
-            jp: "アローラのすがた".to_string(),
-        },
-        "Galar" => TranslateText {
-            zh: "伽勒爾的樣子".to_string(),
-            en: "Galarian form".to_string(),
-            jp: "ガラルのすがた".to_string(),
-        },
-        "Hisui" => TranslateText {
-            zh: "洗翠的樣子".to_string(),
-            en: "Hisuian form".to_string(),
-            jp: "ヒスイのすがた".to_string(),
-        },
-        "Paldea" => TranslateText {
-            zh: "帕底亞的樣子".to_string(),
-            en: "Paldea form".to_string(),
-            jp: "パルデアのすがた".to_string(),
-        },
-        "Combat Breed" => TranslateText {
-            zh: "鬥戰種".to_string(),
-            en: "Combat Breed".to_string(),
-            jp: "コンバットしゅ".to_string(),
-        },
-        "Blaze Breed" => TranslateText {
-            zh: "火熾種".to_string(),
-            en: "Blaze Breed".to_string(),
-            jp: "ブレイズしゅ".to_string(),
-        },
-        "Aqua Breed" => TranslateText {
-            zh: "水瀾種".to_string(),
-            en: "Aqua Breed".to_string(),
-            jp: "ウォーターしゅ".to_string(),
-        },
-        "Zen Mode" => TranslateText {
-            zh: "達摩模式".to_string(),
-            en: "Zen Mode".to_string(),
-            jp: "ダルマモード".to_string(),
-        },
-        "Red-Striped" => TranslateText {
-            zh: "紅條紋".to_string(),
-            en: "Red-Striped".to_string(),
-            jp: "あかすじのすがた".to_string(),
-        },
-        "White-Striped" => TranslateText {
-            zh: "白條紋".to_string(),
-            en: "White-Striped".to_string(),
-            jp: "しろすじのすがた".to_string(),
-        },
-        "Blue-Striped" => TranslateText {
-            zh: "藍條紋".to_string(),
-            en: "Blue-Striped".to_string(),
-            jp: "あおすじのすがた".to_string(),
-        },
+        "Alola" => TranslateText::from([
+            ("en", "Alola Form"),
+            ("ja", "アローラのすがた"),
+            ("zh", "阿羅拉的樣子"),
+        ]),
+        "Galar" => TranslateText::from([
+            ("en", "Galarian form"),
+            ("ja", "ガラルのすがた"),
+            ("zh", "伽勒爾的樣子"),
+        ]),
+        "Hisui" => TranslateText::from([
+            ("en", "Hisuian form"),
+            ("ja", "ヒスイのすがた"),
+            ("zh", "洗翠的樣子"),
+        ]),
+        "Paldea" => TranslateText::from([
+            ("en", "Paldea form"),
+            ("ja", "パルデアのすがた"),
+            ("zh", "帕底亞的樣子"),
+        ]),
+        "Combat Breed" => TranslateText::from([
+            ("en", "Combat Breed"),
+            ("ja", "コンバットしゅ"),
+            ("zh", "鬥戰種"),
+        ]),
+        "Blaze Breed" => TranslateText::from([
+            ("en", "Blaze Breed"),
+            ("ja", "ブレイズしゅ"),
+            ("zh", "火熾種"),
+        ]),
+        "Aqua Breed" => TranslateText::from([
+            ("en", "Aqua Breed"),
+            ("ja", "ウォーターしゅ"),
+            ("zh", "水瀾種"),
+        ]),
+        "Zen Mode" => TranslateText::from([
+            ("en", "Zen Mode"),
+            ("ja", "ダルマモード"),
+            ("zh", "達摩模式"),
+        ]),
+        "Red-Striped" => TranslateText::from([
+            ("en", "Red-Striped"),
+            ("ja", "あかすじのすがた"),
+            ("zh", "紅條紋"),
+        ]),
+        "White-Striped" => TranslateText::from([
+            ("en", "White-Striped"),
+            ("ja", "しろすじのすがた"),
+            ("zh", "白條紋"),
+        ]),
+        "Blue-Striped" => TranslateText::from([
+            ("en", "Blue-Striped"),
+            ("ja", "あおすじのすがた"),
+            ("zh", "藍條紋"),
+        ]),
+        _ => TranslateText::default(),
+    }
+}
+
+pub(super) fn translate_game_version<T: AsRef<str>>(version: T) -> TranslateText {
+    match version.as_ref() {
+        "red" => TranslateText::from([("en", "Red"), ("ja", "赤"), ("zh", "紅")]),
+        "green" => TranslateText::from([("en", "Green"), ("ja", "緑"), ("zh", "綠")]),
+        "blue" => TranslateText::from([("en", "Blue"), ("ja", "青"), ("zh", "藍")]),
+        "yellow" => TranslateText::from([("en", "Yellow"), ("ja", "ピカチュウ"), ("zh", "皮卡丘")]),
+        "gold" => TranslateText::from([("en", "Gold"), ("ja", "金"), ("zh", "金")]),
+        "silver" => TranslateText::from([("en", "Silver"), ("ja", "銀"), ("zh", "銀")]),
+        "crystal" => TranslateText::from([("en", "Crystal"), ("ja", "クリスタル"), ("zh", "水晶")]),
+        "ruby" => TranslateText::from([("en", "Ruby"), ("ja", "ルビー"), ("zh", "紅寶石")]),
+        "sapphire" => {
+            TranslateText::from([("en", "Sapphire"), ("ja", "サファイア"), ("zh", "藍寶石")])
+        }
+        "emerald" => {
+            TranslateText::from([("en", "Emerald"), ("ja", "エメラルド"), ("zh", "綠寶石")])
+        }
+        "firered" => {
+            TranslateText::from([("en", "FireRed"), ("ja", "ファイアレッド"), ("zh", "火紅")])
+        }
+        "leafgreen" => TranslateText::from([
+            ("en", "LeafGreen"),
+            ("ja", "リーフグリーン"),
+            ("zh", "葉綠"),
+        ]),
+        "diamond" => {
+            TranslateText::from([("en", "Diamond"), ("ja", "ダイヤモンド"), ("zh", "鑽石")])
+        }
+        "pearl" => TranslateText::from([("en", "Pearl"), ("ja", "パール"), ("zh", "珍珠")]),
+        "platinum" => TranslateText::from([("en", "Platinum"), ("ja", "プラチナ"), ("zh", "白金")]),
+        "heartgold" => TranslateText::from([
+            ("en", "HeartGold"),
+            ("ja", "ハートゴールド"),
+            ("zh", "心金"),
+        ]),
+        "soulsilver" => TranslateText::from([
+            ("en", "SoulSilver"),
+            ("ja", "ソウルシルバー"),
+            ("zh", "魂銀"),
+        ]),
+        "black" => TranslateText::from([("en", "Black"), ("ja", "ブラック"), ("zh", "黑")]),
+        "white" => TranslateText::from([("en", "White"), ("ja", "ホワイト"), ("zh", "白")]),
+        "black-2" => TranslateText::from([("en", "Black 2"), ("ja", "ブラック2"), ("zh", "黑2")]),
+        "white-2" => TranslateText::from([("en", "White 2"), ("ja", "ホワイト2"), ("zh", "白2")]),
+        "x" => TranslateText::from([("en", "X"), ("ja", "X"), ("zh", "X")]),
+        "y" => TranslateText::from([("en", "Y"), ("ja", "Y"), ("zh", "Y")]),
+        "omega-ruby" => TranslateText::from([
+            ("en", "Omega Ruby"),
+            ("ja", "オメガルビー"),
+            ("zh", "終極紅寶石"),
+        ]),
+        "alpha-sapphire" => TranslateText::from([
+            ("en", "Alpha Sapphire"),
+            ("ja", "アルファサファイア"),
+            ("zh", "始源藍寶石"),
+        ]),
+        "sun" => TranslateText::from([("en", "Sun"), ("ja", "サン"), ("zh", "太陽")]),
+        "moon" => TranslateText::from([("en", "Moon"), ("ja", "ムーン"), ("zh", "月亮")]),
+        "ultra-sun" => TranslateText::from([
+            ("en", "Ultra Sun"),
+            ("ja", "ウルトラサン"),
+            ("zh", "究極之日"),
+        ]),
+        "ultra-moon" => TranslateText::from([
+            ("en", "Ultra Moon"),
+            ("ja", "ウルトラムーン"),
+            ("zh", "究極之月"),
+        ]),
+        "lets-go-pikachu" => TranslateText::from([
+            ("en", "Let's Go, Pikachu!"),
+            ("ja", "Let's Go! ピカチュウ"),
+            ("zh", "Let's Go! 皮卡丘"),
+        ]),
+        "lets-go-eevee" => TranslateText::from([
+            ("en", "Let's Go, Eevee!"),
+            ("ja", "Let's Go! イーブイ"),
+            ("zh", "Let's Go! 伊布"),
+        ]),
+        "sword" => TranslateText::from([("en", "Sword"), ("ja", "ソード"), ("zh", "劍")]),
+        "shield" => TranslateText::from([("en", "Shield"), ("ja", "シールド"), ("zh", "盾")]),
+        "legends-arceus" => TranslateText::from([
+            ("en", "Legends: Arceus"),
+            ("ja", "LEGENDS アルセウス"),
+            ("zh", "傳說 阿爾宙斯"),
+        ]),
+        "scarlet" => TranslateText::from([("en", "Scarlet"), ("ja", "スカーレット"), ("zh", "朱")]),
+        "violet" => TranslateText::from([("en", "Violet"), ("ja", "バイオレット"), ("zh", "紫")]),
+        _ => TranslateText::default(),
+    }
+}
+
+pub(super) fn translate_regional_dex<T: AsRef<str>>(dex: T) -> TranslateText {
+    match dex.as_ref() {
+        "national" => TranslateText::from([("en", "National"), ("ja", "全国"), ("zh", "全國")]),
+        "kanto" => TranslateText::from([("en", "Kanto"), ("ja", "カントー"), ("zh", "關都")]),
+        "johto" => TranslateText::from([("en", "Johto"), ("ja", "ジョウト"), ("zh", "城都")]),
+        "hoenn" => TranslateText::from([("en", "Hoenn"), ("ja", "ホウエン"), ("zh", "豐緣")]),
+        "sinnoh" => TranslateText::from([("en", "Sinnoh"), ("ja", "シンオウ"), ("zh", "神奧")]),
+        "unova" => TranslateText::from([("en", "Unova"), ("ja", "イッシュ"), ("zh", "合眾")]),
+        "kalos" => TranslateText::from([("en", "Kalos"), ("ja", "カロス"), ("zh", "卡洛斯")]),
+        "alola" => TranslateText::from([("en", "Alola"), ("ja", "アローラ"), ("zh", "阿羅拉")]),
+        "galar" => TranslateText::from([("en", "Galar"), ("ja", "ガラル"), ("zh", "伽勒爾")]),
+        "hisui" => TranslateText::from([("en", "Hisui"), ("ja", "ヒスイ"), ("zh", "洗翠")]),
+        "paldea" => TranslateText::from([("en", "Paldea"), ("ja", "パルデア"), ("zh", "帕底亞")]),
+        "kitakami" => TranslateText::from([("en", "Kitakami"), ("ja", "キタカミ"), ("zh", "北上")]),
+        "blueberry" => {
+            TranslateText::from([("en", "Blueberry"), ("ja", "ブルーベリー"), ("zh", "藍莓")])
+        }
         _ => TranslateText::default(),
     }
 }
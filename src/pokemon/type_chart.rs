@@ -0,0 +1,183 @@
+use std::fmt::{self, Display};
+
+use super::{PokemonType, PokemonTypeKind};
+
+/// damage multiplier of a move against a pokemon
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Effectiveness {
+    NoEffect,
+    Quarter,
+    Half,
+    Normal,
+    Double,
+    Quadruple,
+}
+
+impl Effectiveness {
+    pub const ALL: [Effectiveness; 6] = [
+        Effectiveness::NoEffect,
+        Effectiveness::Quarter,
+        Effectiveness::Half,
+        Effectiveness::Normal,
+        Effectiveness::Double,
+        Effectiveness::Quadruple,
+    ];
+
+    /// power of 2 of the multiplier, `None` for no effect
+    fn power(self) -> Option<i8> {
+        match self {
+            Effectiveness::NoEffect => None,
+            Effectiveness::Quarter => Some(-2),
+            Effectiveness::Half => Some(-1),
+            Effectiveness::Normal => Some(0),
+            Effectiveness::Double => Some(1),
+            Effectiveness::Quadruple => Some(2),
+        }
+    }
+
+    fn from_power(power: Option<i8>) -> Self {
+        match power {
+            None => Effectiveness::NoEffect,
+            Some(..=-2) => Effectiveness::Quarter,
+            Some(-1) => Effectiveness::Half,
+            Some(0) => Effectiveness::Normal,
+            Some(1) => Effectiveness::Double,
+            Some(2..) => Effectiveness::Quadruple,
+        }
+    }
+
+    /// multiplier against both types of a dual type pokemon
+    pub fn combine(self, other: Effectiveness) -> Self {
+        let power = self.power().zip(other.power()).map(|(a, b)| a + b);
+        Self::from_power(power)
+    }
+}
+
+impl Display for Effectiveness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Effectiveness::NoEffect => write!(f, "0x"),
+            Effectiveness::Quarter => write!(f, "1/4x"),
+            Effectiveness::Half => write!(f, "1/2x"),
+            Effectiveness::Normal => write!(f, "1x"),
+            Effectiveness::Double => write!(f, "2x"),
+            Effectiveness::Quadruple => write!(f, "4x"),
+        }
+    }
+}
+
+impl PokemonTypeKind {
+    /// multiplier of a move of this type against a single type, as of generation 6
+    pub fn against(self, defense: PokemonTypeKind) -> Effectiveness {
+        use PokemonTypeKind::*;
+
+        let (double, half, none): (&[PokemonTypeKind], &[PokemonTypeKind], &[PokemonTypeKind]) =
+            match self {
+                Normal => (&[], &[Rock, Steel], &[Ghost]),
+                Fire => (&[Grass, Ice, Bug, Steel], &[Fire, Water, Rock, Dragon], &[]),
+                Water => (&[Fire, Ground, Rock], &[Water, Grass, Dragon], &[]),
+                Electric => (&[Water, Flying], &[Electric, Grass, Dragon], &[Ground]),
+                Grass => (
+                    &[Water, Ground, Rock],
+                    &[Fire, Grass, Poison, Flying, Bug, Dragon, Steel],
+                    &[],
+                ),
+                Ice => (
+                    &[Grass, Ground, Flying, Dragon],
+                    &[Fire, Water, Ice, Steel],
+                    &[],
+                ),
+                Fighting => (
+                    &[Normal, Ice, Rock, Dark, Steel],
+                    &[Poison, Flying, Psychic, Bug, Fairy],
+                    &[Ghost],
+                ),
+                Poison => (&[Grass, Fairy], &[Poison, Ground, Rock, Ghost], &[Steel]),
+                Ground => (
+                    &[Fire, Electric, Poison, Rock, Steel],
+                    &[Grass, Bug],
+                    &[Flying],
+                ),
+                Flying => (&[Grass, Fighting, Bug], &[Electric, Rock, Steel], &[]),
+                Psychic => (&[Fighting, Poison], &[Psychic, Steel], &[Dark]),
+                Bug => (
+                    &[Grass, Psychic, Dark],
+                    &[Fire, Fighting, Poison, Flying, Ghost, Steel, Fairy],
+                    &[],
+                ),
+                Rock => (&[Fire, Ice, Flying, Bug], &[Fighting, Ground, Steel], &[]),
+                Ghost => (&[Psychic, Ghost], &[Dark], &[Normal]),
+                Dragon => (&[Dragon], &[Steel], &[Fairy]),
+                Dark => (&[Psychic, Ghost], &[Fighting, Dark, Fairy], &[]),
+                Steel => (&[Ice, Rock, Fairy], &[Fire, Water, Electric, Steel], &[]),
+                Fairy => (&[Fighting, Dragon, Dark], &[Fire, Poison, Steel], &[]),
+                Other => (&[], &[], &[]),
+            };
+
+        if double.contains(&defense) {
+            Effectiveness::Double
+        } else if half.contains(&defense) {
+            Effectiveness::Half
+        } else if none.contains(&defense) {
+            Effectiveness::NoEffect
+        } else {
+            Effectiveness::Normal
+        }
+    }
+}
+
+impl PokemonType {
+    /// multiplier of a move of `attack` type against this type combination
+    pub fn damage_from(&self, attack: PokemonTypeKind) -> Effectiveness {
+        let first = attack.against(self.0);
+        match self.1 {
+            Some(second) => first.combine(attack.against(second)),
+            None => first,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_chart() {
+        use PokemonTypeKind::*;
+
+        let cases = [
+            (
+                Ice,
+                PokemonType(Dragon, Some(Flying)),
+                Effectiveness::Quadruple,
+            ),
+            (
+                Ground,
+                PokemonType(Electric, Some(Flying)),
+                Effectiveness::NoEffect,
+            ),
+            (Fire, PokemonType(Water, Some(Rock)), Effectiveness::Quarter),
+            (Water, PokemonType(Fire, Some(Water)), Effectiveness::Normal),
+            (Fighting, PokemonType(Ghost, None), Effectiveness::NoEffect),
+            (Fairy, PokemonType(Dragon, None), Effectiveness::Double),
+            (Steel, PokemonType(Fire, None), Effectiveness::Half),
+        ];
+
+        for (attack, defense, expected) in cases {
+            assert_eq!(
+                defense.damage_from(attack),
+                expected,
+                "{} vs {:?}",
+                attack,
+                defense
+            );
+        }
+
+        // every type is resisted or resists something
+        for t in PokemonTypeKind::ALL {
+            assert!(PokemonTypeKind::ALL
+                .iter()
+                .any(|d| t.against(*d) != Effectiveness::Normal));
+        }
+    }
+}
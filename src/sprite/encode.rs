@@ -0,0 +1,117 @@
+use std::fmt::Write;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// largest base64 payload of one kitty escape
+const KITTY_CHUNK: usize = 4096;
+
+/// delete every kitty image on screen
+pub const KITTY_CLEAR: &str = "\x1b_Ga=d,d=A,q=2\x1b\\";
+
+/// png shown over `cols` x `rows` cells with the kitty graphics protocol,
+/// replacing the image shown before
+pub fn kitty(png: &[u8], cols: u16, rows: u16) -> String {
+    let payload = STANDARD.encode(png);
+    let chunks = payload.as_bytes().chunks(KITTY_CHUNK).collect::<Vec<_>>();
+
+    let mut escape = String::from(KITTY_CLEAR);
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = (i + 1 < chunks.len()) as u8;
+        // q=2 keeps the terminal from answering on stdin
+        let control = if i == 0 {
+            format!("a=T,f=100,q=2,c={},r={},m={}", cols, rows, more)
+        } else {
+            format!("m={}", more)
+        };
+        let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+        let _ = write!(escape, "\x1b_G{};{}\x1b\\", control, chunk);
+    }
+
+    escape
+}
+
+/// png shown over `cols` x `rows` cells as an iterm2 inline image
+pub fn iterm2(png: &[u8], cols: u16, rows: u16) -> String {
+    format!(
+        "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=1:{}\x07",
+        png.len(),
+        cols,
+        rows,
+        STANDARD.encode(png)
+    )
+}
+
+/// rgba pixels as sixel, colors are cut to a 6x6x6 cube and
+/// pixels less than half opaque are left transparent
+pub fn sixel(width: usize, height: usize, rgba: &[u8]) -> String {
+    // palette index of each pixel
+    let pixels = rgba
+        .chunks_exact(4)
+        .take(width * height)
+        .map(|p| (p[3] >= 128).then(|| cube_index(p[0], p[1], p[2])))
+        .collect::<Vec<_>>();
+
+    let mut used = pixels.iter().flatten().copied().collect::<Vec<_>>();
+    used.sort_unstable();
+    used.dedup();
+
+    // P2=1 keeps the pixels left out, raster attributes make them square
+    let mut escape = format!("\x1bP0;1;0q\"1;1;{};{}", width, height);
+    for &index in used.iter() {
+        let [r, g, b] = cube_color(index).map(|c| c as u32 * 100 / 255);
+        let _ = write!(escape, "#{};2;{};{};{}", index, r, g, b);
+    }
+
+    for band in (0..height).step_by(6) {
+        if band > 0 {
+            escape.push('-');
+        }
+
+        let rows = band..(band + 6).min(height);
+        for &index in used.iter() {
+            let sixels = (0..width)
+                .map(|x| {
+                    rows.clone().fold(0u8, |bits, y| {
+                        let set = pixels.get(y * width + x) == Some(&Some(index));
+                        bits | ((set as u8) << (y - band))
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            if sixels.iter().all(|&bits| bits == 0) {
+                continue;
+            }
+
+            let _ = write!(escape, "#{}", index);
+            push_runs(&mut escape, &sixels);
+            escape.push('$');
+        }
+    }
+
+    escape.push_str("\x1b\\");
+    escape
+}
+
+/// sixel characters of a row, runs longer than 3 written as `!<count><char>`
+fn push_runs(escape: &mut String, sixels: &[u8]) {
+    let mut i = 0;
+    while i < sixels.len() {
+        let run = sixels[i..].iter().take_while(|&&s| s == sixels[i]).count();
+        let c = (63 + sixels[i]) as char;
+        if run > 3 {
+            let _ = write!(escape, "!{}{}", run, c);
+        } else {
+            escape.extend(std::iter::repeat_n(c, run));
+        }
+        i += run;
+    }
+}
+
+fn cube_index(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| (c as u16 * 5 + 127) / 255;
+    (level(r) * 36 + level(g) * 6 + level(b)) as u8
+}
+
+fn cube_color(index: u8) -> [u8; 3] {
+    [index / 36, index / 6 % 6, index % 6].map(|level| level * 51)
+}
@@ -0,0 +1,223 @@
+mod encode;
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    path::PathBuf,
+    rc::Rc,
+};
+
+use crate::state::art::{ArtKey, ART_CACHE_SIZE};
+
+pub use encode::{iterm2, kitty, sixel, KITTY_CLEAR};
+
+/// rows a sprite is scaled up towards, a whole number of times so pixels stay square
+pub const SPRITE_ROWS: u16 = 16;
+
+/// pixels of a cell when the terminal does not report its size
+pub const DEFAULT_CELL_SIZE: (u16, u16) = (8, 16);
+
+/// escape sequences a terminal can draw images with
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ImageProtocol {
+    Kitty,
+    Iterm2,
+    Sixel,
+}
+
+impl ImageProtocol {
+    pub const ALL: [ImageProtocol; 3] = [
+        ImageProtocol::Kitty,
+        ImageProtocol::Iterm2,
+        ImageProtocol::Sixel,
+    ];
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.to_string().eq_ignore_ascii_case(name))
+    }
+
+    /// protocol of the terminal guessed from its environment variables, read with `var`
+    ///
+    /// none inside tmux or screen, which only pass images through when set up to
+    pub fn detect(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let term = var("TERM").unwrap_or_default();
+        let program = var("TERM_PROGRAM").unwrap_or_default();
+
+        if var("TMUX").is_some() || term.starts_with("screen") || term.starts_with("tmux") {
+            None
+        } else if var("KITTY_WINDOW_ID").is_some() || term.contains("kitty") || program == "ghostty"
+        {
+            Some(ImageProtocol::Kitty)
+        } else if program == "iTerm.app"
+            || program == "WezTerm"
+            || var("LC_TERMINAL").is_some_and(|t| t == "iTerm2")
+        {
+            Some(ImageProtocol::Iterm2)
+        } else if ["foot", "mlterm", "sixel", "contour"]
+            .iter()
+            .any(|t| term.contains(t))
+        {
+            Some(ImageProtocol::Sixel)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for ImageProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImageProtocol::Kitty => write!(f, "kitty"),
+            ImageProtocol::Iterm2 => write!(f, "iterm2"),
+            ImageProtocol::Sixel => write!(f, "sixel"),
+        }
+    }
+}
+
+/// a png file and its pixels
+pub struct Sprite {
+    pub png: Vec<u8>,
+    pub width: usize,
+    pub height: usize,
+    /// 4 bytes a pixel, row by row
+    pub rgba: Vec<u8>,
+}
+
+impl Sprite {
+    pub fn decode(png: Vec<u8>) -> Result<Self, png::DecodingError> {
+        let mut decoder = png::Decoder::new(png.as_slice());
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+        buffer.truncate(info.buffer_size());
+
+        let rgba = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer
+                .chunks_exact(3)
+                .flat_map(|p| [p[0], p[1], p[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => buffer
+                .chunks_exact(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            png::ColorType::Grayscale | png::ColorType::Indexed => {
+                buffer.iter().flat_map(|&g| [g, g, g, 255]).collect()
+            }
+        };
+
+        Ok(Self {
+            png,
+            width: info.width as usize,
+            height: info.height as usize,
+            rgba,
+        })
+    }
+
+    /// pixels scaled up `factor` times by repeating them
+    pub fn scaled(&self, factor: usize) -> Vec<u8> {
+        let mut rgba = Vec::with_capacity(self.rgba.len() * factor * factor);
+        for row in self.rgba.chunks_exact(self.width * 4) {
+            let scaled_row = row
+                .chunks_exact(4)
+                .flat_map(|pixel| std::iter::repeat_n(pixel, factor).flatten())
+                .copied()
+                .collect::<Vec<_>>();
+            for _ in 0..factor {
+                rgba.extend_from_slice(&scaled_row);
+            }
+        }
+        rgba
+    }
+}
+
+/// a sprite encoded for the terminal and the cells it covers
+#[derive(Debug, PartialEq)]
+pub struct SpriteImage {
+    pub cols: u16,
+    pub rows: u16,
+    pub escape: String,
+}
+
+/// png sprites of a directory laid out like the colorscripts one,
+/// `<dir>/<regular|shiny>/<name>.png`
+pub struct SpriteRenderer {
+    pub protocol: ImageProtocol,
+    pub dir: PathBuf,
+    /// pixels of a cell, width and height
+    pub cell_size: (u16, u16),
+    /// off shows the ansi art
+    pub enabled: bool,
+    /// missing and broken files are cached as `None` too
+    images: HashMap<PathBuf, Option<Rc<SpriteImage>>>,
+}
+
+impl SpriteRenderer {
+    pub fn new(protocol: ImageProtocol, dir: PathBuf) -> Self {
+        Self {
+            protocol,
+            dir,
+            cell_size: DEFAULT_CELL_SIZE,
+            enabled: true,
+            images: HashMap::new(),
+        }
+    }
+
+    /// pixels of a cell, sizes of 0 keep the default
+    pub fn cell_size(mut self, width: u16, height: u16) -> Self {
+        if width > 0 && height > 0 {
+            self.cell_size = (width, height);
+        }
+        self
+    }
+
+    pub fn path(&self, key: &ArtKey) -> PathBuf {
+        self.dir
+            .join(key.colors())
+            .join(format!("{}.png", key.file()))
+    }
+
+    pub fn exists(&self, key: &ArtKey) -> bool {
+        self.path(key).is_file()
+    }
+
+    /// escape sequence of `sprite`, scaled up towards `SPRITE_ROWS`
+    pub fn encode(&self, sprite: &Sprite) -> SpriteImage {
+        let (cell_width, cell_height) = (self.cell_size.0 as usize, self.cell_size.1 as usize);
+        let factor = (SPRITE_ROWS as usize * cell_height / sprite.height.max(1)).max(1);
+        let (width, height) = (sprite.width * factor, sprite.height * factor);
+        let cols = width.div_ceil(cell_width) as u16;
+        let rows = height.div_ceil(cell_height) as u16;
+
+        let escape = match self.protocol {
+            ImageProtocol::Kitty => kitty(&sprite.png, cols, rows),
+            ImageProtocol::Iterm2 => iterm2(&sprite.png, cols, rows),
+            ImageProtocol::Sixel => sixel(width, height, &sprite.scaled(factor)),
+        };
+
+        SpriteImage { cols, rows, escape }
+    }
+
+    /// encoded sprite of `key`, read from disk if it is not cached
+    pub fn get(&mut self, key: &ArtKey) -> Option<Rc<SpriteImage>> {
+        let path = self.path(key);
+        if let Some(image) = self.images.get(&path) {
+            return image.clone();
+        }
+
+        let image = std::fs::read(&path)
+            .ok()
+            .and_then(|png| Sprite::decode(png).ok())
+            .map(|sprite| Rc::new(self.encode(&sprite)));
+
+        // encoded sprites are large, start over rather than keep every one
+        if self.images.len() >= ART_CACHE_SIZE {
+            self.images.clear();
+        }
+        self.images.insert(path, image.clone());
+        image
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    path::PathBuf,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use ansi_to_tui::IntoText;
+use ratatui::text::Text;
+
+use crate::data::ArtArchive;
+
+/// parsed arts kept in memory
+pub const ART_CACHE_SIZE: usize = 64;
+
+/// an art file, `root` is the directory of a size like `colorscripts/small`
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ArtKey {
+    pub name: String,
+    /// `regular` or a form like `gmax`
+    pub form: String,
+    pub shiny: bool,
+    pub root: PathBuf,
+}
+
+impl ArtKey {
+    pub fn file(&self) -> String {
+        if self.form == "regular" || self.form.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}-{}", self.name, self.form)
+        }
+    }
+
+    /// `regular` or `shiny`, the directory of the colors
+    pub fn colors(&self) -> &'static str {
+        if self.shiny {
+            "shiny"
+        } else {
+            "regular"
+        }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        self.root.join(self.colors()).join(self.file())
+    }
+
+    /// name in an art archive, `<size>/<colors>/<file>` where size is the name of `root`
+    pub fn entry(&self) -> String {
+        let size = self.root.file_name().unwrap_or_default().to_string_lossy();
+        format!("{}/{}/{}", size, self.colors(), self.file())
+    }
+}
+
+/// hits and reads of the cache
+#[derive(Default, Clone, Copy, Debug)]
+pub struct ArtStats {
+    pub hits: u32,
+    pub loads: u32,
+    pub prefetched: u32,
+    /// time spent reading and parsing files
+    pub load_time: Duration,
+    pub slowest: Duration,
+}
+
+impl ArtStats {
+    pub fn average(&self) -> Duration {
+        self.load_time.checked_div(self.loads).unwrap_or_default()
+    }
+}
+
+impl Display for ArtStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "art cache: {} hits, {} loads ({} prefetched), {:.1?} average, {:.1?} slowest",
+            self.hits,
+            self.loads,
+            self.prefetched,
+            self.average(),
+            self.slowest
+        )
+    }
+}
+
+/// least recently used arts, missing files are cached as `None` too
+pub struct ArtCache {
+    /// read when a file is not in the art directory
+    pub archive: Option<ArtArchive>,
+    capacity: usize,
+    /// art and when it was last used
+    entries: HashMap<ArtKey, (u64, Option<Rc<Text<'static>>>)>,
+    clock: u64,
+    pub stats: ArtStats,
+}
+
+impl Default for ArtCache {
+    fn default() -> Self {
+        Self::new(ART_CACHE_SIZE)
+    }
+}
+
+impl ArtCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            archive: None,
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            clock: 0,
+            stats: ArtStats::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains(&self, key: &ArtKey) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// the art file exists on disk or in the archive
+    pub fn exists(&self, key: &ArtKey) -> bool {
+        key.path().is_file()
+            || self
+                .archive
+                .as_ref()
+                .is_some_and(|archive| archive.contains(&key.entry()))
+    }
+
+    /// parsed art of `key`, read from disk if it is not cached
+    pub fn get(&mut self, key: &ArtKey) -> Option<Rc<Text<'static>>> {
+        self.clock += 1;
+
+        if let Some((used, art)) = self.entries.get_mut(key) {
+            *used = self.clock;
+            self.stats.hits += 1;
+            return art.clone();
+        }
+
+        self.load(key)
+    }
+
+    /// read the art of `key` ahead of time, without counting it as used
+    pub fn prefetch(&mut self, key: &ArtKey) {
+        if !self.contains(key) {
+            self.stats.prefetched += 1;
+            self.load(key);
+        }
+    }
+
+    fn load(&mut self, key: &ArtKey) -> Option<Rc<Text<'static>>> {
+        let start = Instant::now();
+        let art = std::fs::read(key.path())
+            .ok()
+            .or_else(|| self.archive.as_ref()?.get(&key.entry()))
+            .and_then(|buffer| buffer.into_text().ok())
+            .map(Rc::new);
+
+        let elapsed = start.elapsed();
+        self.stats.loads += 1;
+        self.stats.load_time += elapsed;
+        self.stats.slowest = self.stats.slowest.max(elapsed);
+
+        if self.entries.len() >= self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (used, _))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        self.entries.insert(key.clone(), (self.clock, art.clone()));
+        art
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, fs, path::Path};
+
+    use crate::data::pack;
+
+    use super::*;
+
+    #[test]
+    fn missing_art_is_read_from_archive() {
+        let dir = std::env::temp_dir().join(format!("poketex-art-{}", std::process::id()));
+        let pikachu = fs::read(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("colorscripts/small/regular/pikachu"),
+        )
+        .unwrap();
+        fs::create_dir_all(dir.join("small/regular")).unwrap();
+        fs::write(dir.join("small/regular/pikachu"), &pikachu).unwrap();
+        let bytes = pack(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let mut cache = ArtCache {
+            archive: ArtArchive::from_bytes(Cow::Owned(bytes)).ok(),
+            ..Default::default()
+        };
+        let key = |name: &str| ArtKey {
+            name: name.to_string(),
+            form: String::from("regular"),
+            shiny: false,
+            root: dir.join("small"),
+        };
+
+        assert!(!key("pikachu").path().exists());
+        assert!(cache.exists(&key("pikachu")));
+        assert!(cache.get(&key("pikachu")).is_some());
+        assert!(!cache.exists(&key("raichu")));
+        assert!(cache.get(&key("raichu")).is_none());
+    }
+}
@@ -0,0 +1,40 @@
+use std::rc::Rc;
+
+use crate::pokemon::PokemonEntity;
+
+use super::pokemon::ScrollableParagraphState;
+
+#[derive(Default)]
+pub struct BreedingState {
+    pub mother: Option<Rc<PokemonEntity>>,
+    pub father: Option<Rc<PokemonEntity>>,
+    pub scroll: ScrollableParagraphState,
+}
+
+impl BreedingState {
+    /// first pick is the mother and the second is the father
+    pub fn pick(&mut self, pm: Rc<PokemonEntity>) {
+        if self.mother.is_none() || self.is_ready() {
+            self.mother = Some(pm);
+            self.father = None;
+        } else {
+            self.father = Some(pm);
+        }
+
+        self.scroll.reset();
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.mother.is_some() && self.father.is_some()
+    }
+
+    pub fn is_picking(&self) -> bool {
+        self.mother.is_some() && self.father.is_none()
+    }
+
+    pub fn reset(&mut self) {
+        self.mother = None;
+        self.father = None;
+        self.scroll.reset();
+    }
+}
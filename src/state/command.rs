@@ -0,0 +1,305 @@
+use std::fmt::{self, Display};
+
+use tui_input::Input;
+
+use crate::{
+    i18n::trf,
+    pokemon::{AltName, Locale, SortKey},
+};
+
+/// names of `:` commands, for completion
+pub const COMMANDS: [&str; 12] = [
+    "also", "cache", "compare", "goto", "help", "lang", "matchup", "quit", "quiz", "set", "sort",
+    "type",
+];
+
+/// options of `:set`
+pub const OPTIONS: [&str; 9] = [
+    "abilities",
+    "column",
+    "dex",
+    "help",
+    "info",
+    "iv",
+    "list",
+    "shiny",
+    "sprites",
+];
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    /// filter list by one or two types, no type clears the filter
+    Type(Vec<String>),
+    Sort(SortKey),
+    Lang(Locale),
+    /// names shown besides the one of the locale, none hides them
+    Also(Vec<AltName>),
+    /// pokemon name in any language, or dex number
+    Goto(String),
+    Set {
+        option: String,
+        value: SetValue,
+    },
+    /// compare the selected pokemon with another one, or pick it when there is none
+    Compare(Option<String>),
+    /// who's that pokemon, of the generations or all of them
+    Quiz(Vec<u8>),
+    /// type effectiveness trainer
+    Matchup,
+    /// hits and load times of the art cache
+    Cache,
+    Help,
+    Quit,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SetValue {
+    On,
+    Off,
+    Toggle,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CommandError {
+    Unknown(String),
+    /// option of `:set` not in [`OPTIONS`]
+    UnknownOption(String),
+    MissingArgument(&'static str),
+    InvalidArgument {
+        command: &'static str,
+        value: String,
+    },
+    NotFound(String),
+}
+
+impl CommandError {
+    /// the error in the language of `locale`
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            CommandError::Unknown(cmd) => trf(locale, "command.unknown", &[cmd]),
+            CommandError::UnknownOption(option) => trf(locale, "command.unknown_option", &[option]),
+            CommandError::MissingArgument(cmd) => trf(locale, "command.missing_argument", &[cmd]),
+            CommandError::InvalidArgument { command, value } => {
+                trf(locale, "command.invalid_argument", &[command, value])
+            }
+            CommandError::NotFound(name) => trf(locale, "command.not_found", &[name]),
+        }
+    }
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message(Locale::default()))
+    }
+}
+
+impl Command {
+    pub fn parse(line: &str) -> Result<Self, CommandError> {
+        let line = line.trim();
+        let (name, arg) = match line.split_once(char::is_whitespace) {
+            Some((name, arg)) => (name, arg.trim()),
+            None => (line, ""),
+        };
+
+        // `:25` jumps like `25G`
+        if name.parse::<u16>().is_ok() && arg.is_empty() {
+            return Ok(Command::Goto(name.to_string()));
+        }
+
+        match complete_name(name)? {
+            "type" => {
+                let types = arg.split_whitespace().map(str::to_lowercase);
+                Ok(Command::Type(types.collect()))
+            }
+            "sort" => SortKey::parse(arg)
+                .map(Command::Sort)
+                .ok_or_else(|| invalid("sort", arg)),
+            "lang" => Locale::parse(arg)
+                .map(Command::Lang)
+                .ok_or_else(|| invalid("lang", arg)),
+            "also" => arg
+                .split_whitespace()
+                .map(|code| AltName::parse(code).ok_or_else(|| invalid("also", code)))
+                .collect::<Result<_, _>>()
+                .map(Command::Also),
+            "goto" if arg.is_empty() => Err(CommandError::MissingArgument("goto")),
+            "goto" => Ok(Command::Goto(arg.to_string())),
+            "set" => {
+                let (option, value) = if let Some(option) = arg.strip_suffix('!') {
+                    (option, SetValue::Toggle)
+                } else if let Some(option) = arg.strip_prefix("no") {
+                    (option, SetValue::Off)
+                } else {
+                    (arg, SetValue::On)
+                };
+
+                if OPTIONS.contains(&option) {
+                    Ok(Command::Set {
+                        option: option.to_string(),
+                        value,
+                    })
+                } else {
+                    Err(CommandError::UnknownOption(option.to_string()))
+                }
+            }
+            "compare" if arg.is_empty() => Ok(Command::Compare(None)),
+            "compare" => Ok(Command::Compare(Some(arg.to_string()))),
+            "quiz" => arg
+                .split_whitespace()
+                .map(|g| g.parse().map_err(|_| invalid("quiz", g)))
+                .collect::<Result<_, _>>()
+                .map(Command::Quiz),
+            "matchup" => Ok(Command::Matchup),
+            "cache" => Ok(Command::Cache),
+            "help" => Ok(Command::Help),
+            "quit" => Ok(Command::Quit),
+            _ => Err(CommandError::Unknown(name.to_string())),
+        }
+    }
+}
+
+/// commands can be shortened as long as the prefix is unique, like `:so spd`
+pub fn complete_name(name: &str) -> Result<&'static str, CommandError> {
+    if name.is_empty() {
+        return Err(CommandError::Unknown(String::new()));
+    }
+
+    let mut matched = COMMANDS.iter().filter(|c| c.starts_with(name));
+    match (matched.next(), matched.next()) {
+        (Some(cmd), None) => Ok(cmd),
+        _ if name == "q" => Ok("quit"),
+        _ => Err(CommandError::Unknown(name.to_string())),
+    }
+}
+
+fn invalid(command: &'static str, value: &str) -> CommandError {
+    CommandError::InvalidArgument {
+        command,
+        value: value.to_string(),
+    }
+}
+
+/// result of the last command, shown in place of the search hint
+pub enum CommandMessage {
+    Info(String),
+    Error(String),
+}
+
+struct Completion {
+    /// text before the completed word
+    prefix: String,
+    candidates: Vec<String>,
+    index: usize,
+}
+
+#[derive(Default)]
+pub struct CommandLineState {
+    pub input: Input,
+    pub history: Vec<String>,
+    history_index: Option<usize>,
+    completion: Option<Completion>,
+    pub message: Option<CommandMessage>,
+}
+
+impl CommandLineState {
+    /// take the input and remember it in history
+    pub fn submit(&mut self) -> String {
+        let line = self.input.value().trim().to_string();
+        self.reset();
+
+        if !line.is_empty() && self.history.last() != Some(&line) {
+            self.history.push(line.clone());
+        }
+
+        line
+    }
+
+    pub fn reset(&mut self) {
+        self.input.reset();
+        self.history_index = None;
+        self.completion = None;
+    }
+
+    pub fn history_previous(&mut self) {
+        let index = match self.history_index {
+            Some(i) => i.saturating_sub(1),
+            None if self.history.is_empty() => return,
+            None => self.history.len() - 1,
+        };
+
+        self.history_index = Some(index);
+        self.input = Input::new(self.history[index].clone());
+    }
+
+    pub fn history_next(&mut self) {
+        let Some(i) = self.history_index else {
+            return;
+        };
+
+        if i + 1 < self.history.len() {
+            self.history_index = Some(i + 1);
+            self.input = Input::new(self.history[i + 1].clone());
+        } else {
+            self.history_index = None;
+            self.input.reset();
+        }
+    }
+
+    pub fn is_completing(&self) -> bool {
+        self.completion.is_some()
+    }
+
+    /// complete the word being typed, `prefix` is the text before it
+    pub fn start_completion(&mut self, prefix: String, candidates: Vec<String>, backward: bool) {
+        let index = if backward {
+            candidates.len().saturating_sub(1)
+        } else {
+            0
+        };
+
+        self.completion = Some(Completion {
+            prefix,
+            candidates,
+            index,
+        });
+        self.apply_completion();
+    }
+
+    /// next or previous candidate of the running completion
+    pub fn cycle_completion(&mut self, backward: bool) {
+        if let Some(c) = &mut self.completion {
+            let len = c.candidates.len().max(1);
+            c.index = if backward {
+                (c.index + len - 1) % len
+            } else {
+                (c.index + 1) % len
+            };
+        }
+
+        self.apply_completion();
+    }
+
+    fn apply_completion(&mut self) {
+        if let Some(c) = &self.completion {
+            if let Some(candidate) = c.candidates.get(c.index) {
+                self.input = Input::new(format!("{}{}", c.prefix, candidate));
+            }
+        }
+    }
+
+    /// any key other than tab ends the completion
+    pub fn reset_completion(&mut self) {
+        self.completion = None;
+    }
+}
+
+/// candidates among `words` starting with `word`, case insensitive
+pub fn candidates<'a>(word: &str, words: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let word = word.to_lowercase();
+
+    words
+        .into_iter()
+        .filter(|w| w.to_lowercase().starts_with(&word))
+        .map(str::to_string)
+        .collect()
+}
@@ -0,0 +1,34 @@
+use std::rc::Rc;
+
+use crate::pokemon::PokemonEntity;
+
+#[derive(Default)]
+pub struct CompareState {
+    pub left: Option<Rc<PokemonEntity>>,
+    pub right: Option<Rc<PokemonEntity>>,
+}
+
+impl CompareState {
+    /// first pick is shown on the left and the second on the right
+    pub fn pick(&mut self, pm: Rc<PokemonEntity>) {
+        if self.left.is_none() || self.is_ready() {
+            self.left = Some(pm);
+            self.right = None;
+        } else {
+            self.right = Some(pm);
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.left.is_some() && self.right.is_some()
+    }
+
+    pub fn is_picking(&self) -> bool {
+        self.left.is_some() && self.right.is_none()
+    }
+
+    pub fn reset(&mut self) {
+        self.left = None;
+        self.right = None;
+    }
+}
@@ -1,4 +1,12 @@
+pub mod art;
+pub mod breeding;
+pub mod command;
+pub mod compare;
 pub mod pokemon;
+pub mod quiz;
+pub mod record;
+pub mod timer;
+pub mod trainer;
 pub mod tui;
 
 pub use pokemon::PokemonListState;
@@ -6,13 +14,32 @@ use regex::Regex;
 
 use tui_input::Input;
 
-use self::tui::TuiState;
+use crate::{
+    i18n::{tr, trf},
+    pokemon::{Locale, PokemonTypeKind, SortKey},
+};
+
+use self::{
+    breeding::BreedingState,
+    command::{
+        candidates, complete_name, Command, CommandError, CommandLineState, CommandMessage,
+        SetValue, COMMANDS, OPTIONS,
+    },
+    compare::CompareState,
+    quiz::QuizState,
+    timer::{Timer, Timers},
+    trainer::TrainerState,
+    tui::{Pane, TuiState},
+};
 
 #[derive(Default, Copy, Clone)]
 pub enum InputMode {
     #[default]
     Normal,
     Editing,
+    Command,
+    Quiz,
+    Trainer,
 }
 
 #[derive(Default)]
@@ -25,7 +52,13 @@ pub struct AppState {
     pub tui: TuiState,
     pub key_handle: KeyHandleState,
     pub pokemon_list: PokemonListState,
+    pub breeding: BreedingState,
+    pub compare: CompareState,
+    pub command_line: CommandLineState,
     pub vim_cmd: String,
+    pub timers: Timers,
+    pub quiz: QuizState,
+    pub trainer: TrainerState,
 }
 
 impl AppState {
@@ -35,10 +68,17 @@ impl AppState {
         self.pokemon_list.set_list_filter(String::from(""))
     }
 
+    /// help, a quiz or a result of breeding or compare is shown over the profile
+    pub fn has_popup(&self) -> bool {
+        self.tui.show_help
+            || self.breeding.is_ready()
+            || self.compare.is_ready()
+            || matches!(self.tui.input_mode, InputMode::Quiz | InputMode::Trainer)
+    }
+
+    /// jump to the `i`th item of the list, counted from 1
     pub fn jump(&mut self, i: usize) {
-        if i > 0 || i - 1 > self.pokemon_list.len() {
-            self.pokemon_list.select(i.saturating_sub(1));
-        }
+        self.pokemon_list.select(i.saturating_sub(1));
     }
 
     pub fn command(&mut self, cmd: char) {
@@ -56,7 +96,7 @@ impl AppState {
             s => {
                 if let Some(n) = Regex::new(r"(\d+)G").unwrap().captures(s) {
                     if let Ok(n) = n.get(1).unwrap().as_str().parse::<usize>() {
-                        self.pokemon_list.select(n.saturating_sub(1));
+                        self.jump(n);
                     }
 
                     self.reset_command()
@@ -65,7 +105,294 @@ impl AppState {
         }
     }
 
+    pub fn locale(&self) -> Locale {
+        self.pokemon_list.locale
+    }
+
+    /// switch to the next language and tell which one it is
+    pub fn next_locale(&mut self) {
+        self.pokemon_list.set_locale(self.locale().next());
+        let locale = self.locale();
+        self.set_message(Some(CommandMessage::Info(trf(
+            locale,
+            "message.language",
+            &[&locale],
+        ))));
+    }
+
+    /// show a message in place of the search hint for a while
+    pub fn set_message(&mut self, message: Option<CommandMessage>) {
+        match message {
+            Some(_) => self.timers.start(Timer::Message),
+            None => self.timers.cancel(Timer::Message),
+        }
+        self.command_line.message = message;
+    }
+
+    pub fn on_timer(&mut self, timer: Timer) {
+        match timer {
+            Timer::Message => self.command_line.message = None,
+        }
+    }
+
+    /// j/k scroll the focused pane, panes without anything to scroll move the list
+    pub fn scroll_focused(&mut self, down: bool) {
+        let list = &mut self.pokemon_list;
+
+        if self.breeding.is_ready() {
+            if down {
+                self.breeding.scroll.scroll_down();
+            } else {
+                self.breeding.scroll.scroll_up();
+            }
+            return;
+        }
+
+        match (self.tui.focused(), down) {
+            (Pane::Art, true) => list.increase_ascii_form_index(),
+            (Pane::Art, false) => list.decrease_ascii_form_index(),
+            (Pane::Dex, true) => list.dex_scrollbar_state.scroll_down(),
+            (Pane::Dex, false) => list.dex_scrollbar_state.scroll_up(),
+            (Pane::Abilities, true) => list.desc_scrollbar_state.scroll_down(),
+            (Pane::Abilities, false) => list.desc_scrollbar_state.scroll_up(),
+            (Pane::List | Pane::Stats | Pane::Info, true) => list.next(),
+            (Pane::List | Pane::Stats | Pane::Info, false) => list.previous(),
+        }
+    }
+
+    /// ask pokemon of the generations, all when empty
+    pub fn start_quiz(&mut self, generations: Vec<u8>) -> Result<(), CommandError> {
+        let previous = std::mem::replace(&mut self.quiz.generations, generations);
+
+        if !self.quiz.next(&self.pokemon_list.bundle.pokemon) {
+            let generations = std::mem::replace(&mut self.quiz.generations, previous);
+            let value = generations.iter().map(u8::to_string).collect::<Vec<_>>();
+            return Err(CommandError::InvalidArgument {
+                command: "quiz",
+                value: value.join(" "),
+            });
+        }
+
+        self.tui.input_mode = InputMode::Quiz;
+        Ok(())
+    }
+
+    pub fn next_quiz(&mut self) {
+        self.quiz.next(&self.pokemon_list.bundle.pokemon);
+    }
+
+    /// ask a type matchup, reviews of missed ones come first
+    pub fn start_trainer(&mut self) {
+        if self.trainer.question.is_none() || self.trainer.is_answered() {
+            self.trainer.next(&self.pokemon_list.bundle.pokemon);
+        }
+        self.tui.input_mode = InputMode::Trainer;
+    }
+
+    pub fn next_trainer(&mut self) {
+        self.trainer.next(&self.pokemon_list.bundle.pokemon);
+    }
+
+    pub fn pick_breeding(&mut self) {
+        if self.breeding.is_ready() {
+            self.breeding.reset();
+        } else if let Some(pm) = self.pokemon_list.profile() {
+            self.breeding.pick(pm);
+        }
+    }
+
     pub fn reset_command(&mut self) {
         self.vim_cmd.clear()
     }
+
+    /// run a `:` command, returns a message to show
+    pub fn execute(&mut self, command: Command) -> Result<Option<String>, CommandError> {
+        match command {
+            Command::Type(types) => {
+                if let Some(t) = types.iter().find(|t| Self::type_kind(t).is_none()) {
+                    return Err(CommandError::InvalidArgument {
+                        command: "type",
+                        value: t.clone(),
+                    });
+                }
+
+                let query = types
+                    .iter()
+                    .map(|t| format!("type:{}", t))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                self.key_handle.input = Input::new(query.clone());
+                self.pokemon_list.set_list_filter(query);
+                let count = self.pokemon_list.len();
+                return Ok(Some(trf(self.locale(), "message.count", &[&count])));
+            }
+            Command::Sort(key) => self.pokemon_list.set_sort(key),
+            Command::Lang(locale) => self.pokemon_list.set_locale(locale),
+            Command::Also(alt_names) => self.pokemon_list.alt_names = alt_names,
+            Command::Goto(name) => self.goto(&name)?,
+            Command::Set { option, value } => return self.set(&option, value),
+            Command::Compare(name) => return self.pick_compare(name),
+            Command::Quiz(generations) => self.start_quiz(generations)?,
+            Command::Matchup => self.start_trainer(),
+            Command::Cache => return Ok(Some(self.pokemon_list.art_cache.stats.to_string())),
+            Command::Help => self.tui.show_help = true,
+            Command::Quit => (),
+        }
+
+        Ok(None)
+    }
+
+    fn type_kind(name: &str) -> Option<PokemonTypeKind> {
+        PokemonTypeKind::ALL
+            .into_iter()
+            .find(|t| t.to_string().eq_ignore_ascii_case(name))
+    }
+
+    /// select a pokemon by name or list number, clearing the filter if it hides the pokemon
+    fn goto(&mut self, name: &str) -> Result<(), CommandError> {
+        if let Ok(n) = name.parse::<usize>() {
+            self.jump(n);
+            return Ok(());
+        }
+
+        let pm = self
+            .pokemon_list
+            .bundle
+            .find_by_name(name)
+            .ok_or_else(|| CommandError::NotFound(name.to_string()))?;
+
+        if self.pokemon_list.position(pm.no).is_none() {
+            self.reset();
+        }
+
+        let index = self
+            .pokemon_list
+            .position(pm.no)
+            .ok_or_else(|| CommandError::NotFound(name.to_string()))?;
+        self.pokemon_list.select(index);
+
+        Ok(())
+    }
+
+    fn set(&mut self, option: &str, value: SetValue) -> Result<Option<String>, CommandError> {
+        let apply = |flag: &mut bool| {
+            *flag = match value {
+                SetValue::On => true,
+                SetValue::Off => false,
+                SetValue::Toggle => !*flag,
+            }
+        };
+
+        match option {
+            "abilities" => apply(&mut self.tui.show_abilities),
+            "dex" => apply(&mut self.tui.show_dex),
+            "help" => apply(&mut self.tui.show_help),
+            "info" => apply(&mut self.tui.show_info),
+            "iv" => apply(&mut self.tui.show_iv),
+            "list" => apply(&mut self.tui.show_list),
+            "column" => apply(&mut self.tui.show_list_column),
+            "sprites" => match &mut self.pokemon_list.sprites {
+                Some(sprites) => apply(&mut sprites.enabled),
+                None => return Ok(Some(tr(self.locale(), "message.no_sprites").to_string())),
+            },
+            "shiny" => {
+                let list = &mut self.pokemon_list;
+                let mut shiny = list.shiny;
+                apply(&mut shiny);
+
+                if !list.set_shiny(shiny) {
+                    return Ok(Some(tr(self.locale(), "message.no_shiny").to_string()));
+                }
+            }
+            _ => return Err(CommandError::UnknownOption(option.to_string())),
+        }
+
+        Ok(None)
+    }
+
+    fn pick_compare(&mut self, name: Option<String>) -> Result<Option<String>, CommandError> {
+        let Some(current) = self.pokemon_list.profile() else {
+            return Ok(None);
+        };
+
+        match name {
+            Some(name) => {
+                let other = self
+                    .pokemon_list
+                    .bundle
+                    .find_by_name(&name)
+                    .ok_or(CommandError::NotFound(name))?;
+                self.compare.reset();
+                self.compare.pick(current);
+                self.compare.pick(other);
+                Ok(None)
+            }
+            None => {
+                self.compare.pick(current.clone());
+                if self.compare.is_picking() {
+                    let locale = self.locale();
+                    let name = current.name.get(locale);
+                    Ok(Some(trf(locale, "compare.picked", &[&name])))
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    }
+
+    /// tab completion of the command line
+    pub fn complete_command(&mut self, backward: bool) {
+        if self.command_line.is_completing() {
+            self.command_line.cycle_completion(backward);
+            return;
+        }
+
+        let line = self.command_line.input.value().to_string();
+        let (prefix, candidates) = self.completion(&line);
+        self.command_line
+            .start_completion(prefix, candidates, backward);
+    }
+
+    /// text before the word being completed, and candidates of the word
+    fn completion(&self, line: &str) -> (String, Vec<String>) {
+        let Some((name, arg)) = line.split_once(' ') else {
+            return (String::new(), candidates(line, COMMANDS));
+        };
+
+        let arg = arg.trim_start();
+        let prefix = line[..line.len() - arg.len()].to_string();
+
+        match complete_name(name) {
+            Ok("goto" | "compare") => {
+                let names = self
+                    .pokemon_list
+                    .list_items()
+                    .iter()
+                    .map(|pm| pm.name.get(self.locale()))
+                    .collect::<Vec<_>>();
+                (prefix, candidates(arg, names.iter().map(String::as_str)))
+            }
+            Ok("type") => {
+                // complete the last of several types
+                let word = arg.rsplit(' ').next().unwrap_or("");
+                let prefix = line[..line.len() - word.len()].to_string();
+                let names = PokemonTypeKind::ALL.map(|t| t.to_string().to_lowercase());
+                (prefix, candidates(word, names.iter().map(String::as_str)))
+            }
+            Ok("sort") => (prefix, candidates(arg, SortKey::NAMES)),
+            Ok("lang") => (prefix, candidates(arg, Locale::ALL.map(Locale::code))),
+            Ok("also") => {
+                let word = arg.rsplit(' ').next().unwrap_or("");
+                let prefix = line[..line.len() - word.len()].to_string();
+                let codes = Locale::ALL.map(Locale::code);
+                (
+                    prefix,
+                    candidates(word, codes.into_iter().chain(["romaji"])),
+                )
+            }
+            Ok("set") => (prefix, candidates(arg, OPTIONS)),
+            _ => (prefix, vec![]),
+        }
+    }
 }
@@ -1,8 +1,28 @@
-use std::{path::PathBuf, rc::Rc};
-
-use ratatui::widgets::{ListState, ScrollbarState};
-
-use crate::pokemon::{ascii_form::AsciiForms, AbilityMap, PokemonBundle, PokemonEntity};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use ratatui::{
+    layout::Rect,
+    text::Text,
+    widgets::{ListState, ScrollbarState},
+};
+
+use crate::{
+    data::ArtArchive,
+    pokemon::{
+        ascii_form::AsciiForms, regional::RegionalDex, AbilityMap, AltName, Locale, PokemonBundle,
+        PokemonEntity, PokemonQuery, SortKey,
+    },
+    sprite::{SpriteImage, SpriteRenderer},
+};
+
+use super::art::{ArtCache, ArtKey};
+
+/// pokemon on each side of the selected one whose art is read ahead
+const PREFETCH_DISTANCE: usize = 2;
 
 #[derive(Copy, Clone)]
 pub enum AsciiType {
@@ -14,14 +34,32 @@ pub enum AsciiType {
 pub struct PokemonListState {
     pub list_scrollbar_state: ScrollbarState,
     pub list_state: ListState,
+    /// pokemon of the current dex, in dex order
+    pub pokedex: Vec<Rc<PokemonEntity>>,
+    /// 0 for national dex, otherwise index of regional dex + 1
+    pub dex_index: usize,
+    dex_no: HashMap<u16, u16>,
     pub filtered_list: Vec<Rc<PokemonEntity>>,
     pub filter_query: String,
+    pub sort: SortKey,
+    /// language of names and texts
+    pub locale: Locale,
+    /// names shown besides the one of `locale`
+    pub alt_names: Vec<AltName>,
     pub desc_scrollbar_state: ScrollableParagraphState,
+    pub dex_scrollbar_state: ScrollableParagraphState,
+    pub dex_version_index: usize,
     pub bundle: Rc<PokemonBundle>,
     pub profile_page: u8,
     pub ascii_root: PathBuf,
     pub ascii_form_map: AsciiForms,
     pub ascii_form_index: usize,
+    /// shiny art of whichever form is shown
+    pub shiny: bool,
+    pub art_cache: ArtCache,
+    /// png sprites drawn with a terminal image protocol instead of the art
+    pub sprites: Option<SpriteRenderer>,
+    pub mouse: MouseState,
 }
 
 impl PokemonListState {
@@ -33,9 +71,11 @@ impl PokemonListState {
         list_state.select_first();
 
         let filtered_list = Vec::with_capacity(pokemon_len);
+        let pokedex = bundle.pokemon.clone();
 
         Self {
             bundle,
+            pokedex,
             list_state,
             list_scrollbar_state,
             filtered_list,
@@ -49,19 +89,64 @@ impl PokemonListState {
         self
     }
 
+    /// art archive read for files missing in the art directory
+    pub fn archive(mut self, archive: Option<ArtArchive>) -> Self {
+        self.art_cache.archive = archive;
+        self
+    }
+
+    pub fn sprites(mut self, sprites: Option<SpriteRenderer>) -> Self {
+        self.sprites = sprites;
+        self
+    }
+
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    pub fn alt_names(mut self, alt_names: Vec<AltName>) -> Self {
+        self.alt_names = alt_names;
+        self
+    }
+
+    /// names of `alt_names` that exist and differ from the name in `locale`
+    pub fn alt_names_of(&self, pm: &PokemonEntity) -> Vec<String> {
+        let name = pm.name.get(self.locale);
+
+        self.alt_names
+            .iter()
+            .filter_map(|alt| alt.text(&pm.name))
+            .filter(|alt| *alt != name)
+            .collect()
+    }
+
     pub fn get_assets_path(&self, t: AsciiType) -> PathBuf {
-        self.ascii_root.join(match t {
+        Self::get_assets_path_in(&self.ascii_root, t)
+    }
+
+    /// assets path of a pokemon, which may come from an overlay art directory
+    pub fn get_assets_path_of(&self, name: &str, t: AsciiType) -> PathBuf {
+        match self.ascii_form_map.root(name) {
+            Some(root) => Self::get_assets_path_in(root, t),
+            None => self.get_assets_path(t),
+        }
+    }
+
+    fn get_assets_path_in(root: &Path, t: AsciiType) -> PathBuf {
+        root.join(match t {
             AsciiType::Normal => "regular",
             AsciiType::Shiny => "shiny",
         })
     }
 
+    /// length of the visible list, filtered or not
     pub fn len(&self) -> usize {
-        self.bundle.pokemon.len()
+        self.list_items().len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.bundle.pokemon.is_empty()
+        self.list_items().is_empty()
     }
 
     pub fn ability_map(&self) -> Rc<AbilityMap> {
@@ -69,23 +154,17 @@ impl PokemonListState {
     }
 
     pub fn scroll_to_first(&mut self) {
-        self.list_state.select_first();
+        self.select(0);
     }
 
     pub fn scroll_to_end(&mut self) {
-        self.select(self.len() - 1)
+        self.select(self.len().saturating_sub(1))
     }
 
     pub fn next(&mut self) {
         let index = match self.list_state.selected() {
-            Some(i) => {
-                if i >= self.len() - 1 {
-                    0
-                } else {
-                    i.saturating_add(1)
-                }
-            }
-            None => 0,
+            Some(i) if i.saturating_add(1) < self.len() => i + 1,
+            _ => 0,
         };
 
         self.select(index);
@@ -93,47 +172,22 @@ impl PokemonListState {
 
     pub fn previous(&mut self) {
         let index = match self.list_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    if !self.is_empty() {
-                        self.len() - 1
-                    } else {
-                        i
-                    }
-                } else {
-                    i.saturating_sub(1)
-                }
-            }
-            None => 0,
+            Some(i) if i > 0 => i - 1,
+            _ => self.len().saturating_sub(1),
         };
 
         self.select(index);
     }
 
     pub fn scroll_down(&mut self, amount: u8) {
-        if let Some(i) = self
-            .list_state
-            .selected()
-            .and_then(|v| v.checked_add(amount.into()))
-            .map(|mut index| {
-                if index > self.len() {
-                    index = self.len() - 1;
-                }
-                index
-            })
-        {
-            self.select(i);
+        if let Some(i) = self.list_state.selected() {
+            self.select(i.saturating_add(amount.into()));
         }
     }
 
     pub fn scroll_up(&mut self, amount: u8) {
-        if let Some(i) = self
-            .list_state
-            .selected()
-            .and_then(|v| v.checked_sub(amount.into()))
-            .or(Some(0))
-        {
-            self.select(i);
+        if let Some(i) = self.list_state.selected() {
+            self.select(i.saturating_sub(amount.into()));
         }
     }
 
@@ -141,51 +195,52 @@ impl PokemonListState {
         self.filter_query.clone_from(&filter);
 
         if !filter.is_empty() {
-            self.filtered_list.clear();
-            self.filtered_list.extend(
-                self.bundle
-                    .pokemon
-                    .iter()
-                    .filter(|item| {
-                        item.name_with_no()
-                            .to_lowercase()
-                            .contains(&filter.to_lowercase())
-                    })
-                    .cloned(),
-            );
+            let query = PokemonQuery::parse(&filter);
+            let filtered_list = self
+                .pokedex
+                .iter()
+                .filter(|item| query.matches(item, self.dex_no(item)))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            self.filtered_list = filtered_list;
         };
 
+        self.list_scrollbar_state = self.list_scrollbar_state.content_length(self.len());
         self.select(0);
     }
 
+    /// select `index` of the visible list, clamped to its end
     pub fn select(&mut self, index: usize) {
+        let index = if self.is_empty() {
+            None
+        } else {
+            Some(index.min(self.len() - 1))
+        };
+
         self.profile_page = 0;
         self.desc_scrollbar_state.reset();
-        self.list_state.select(Some(index));
-        self.list_scrollbar_state = self.list_scrollbar_state.position(index);
+        self.reset_dex_version();
+        self.list_state.select(index);
+        self.list_scrollbar_state = self.list_scrollbar_state.position(index.unwrap_or(0));
         self.reset_ascii_form_index();
     }
 
     pub fn is_scroll_head(&self) -> bool {
-        if let Some(i) = self.list_state.selected() {
-            i == 0
-        } else {
-            false
-        }
+        self.list_state.selected() == Some(0)
     }
 
     pub fn is_scroll_tail(&self) -> bool {
-        if let Some(i) = self.list_state.selected() {
-            i == self.len() - 1
-        } else {
-            false
+        match self.list_state.selected() {
+            Some(i) => i.saturating_add(1) == self.len(),
+            None => false,
         }
     }
 
     pub fn profile(&self) -> Option<Rc<PokemonEntity>> {
         let index = self.list_state.selected()?;
         if self.filter_query.is_empty() {
-            self.bundle.pokemon.get(index).cloned()
+            self.pokedex.get(index).cloned()
         } else {
             self.filtered_list.get(index).cloned()
         }
@@ -233,7 +288,7 @@ impl PokemonListState {
 
     pub fn list_items(&self) -> &Vec<Rc<PokemonEntity>> {
         if self.filter_query.is_empty() {
-            &self.bundle.pokemon
+            &self.pokedex
         } else {
             &self.filtered_list
         }
@@ -247,9 +302,238 @@ impl PokemonListState {
         }
     }
 
+    pub fn decrease_ascii_form_index(&mut self) {
+        let len = self.ascii_forms().map_or(1, |forms| forms.len().max(1));
+        self.ascii_form_index = (self.ascii_form_index % len + len - 1) % len;
+    }
+
     pub fn reset_ascii_form_index(&mut self) {
         self.ascii_form_index = 0
     }
+
+    /// ascii forms of the selected pokemon or region form
+    pub fn ascii_forms(&self) -> Option<&Vec<String>> {
+        let profile = self.profile_with_region_form()?;
+        self.ascii_form_map.get(&profile.ascii_name())
+    }
+
+    /// ascii form shown of the selected pokemon
+    pub fn ascii_form(&self) -> Option<&str> {
+        let forms = self.ascii_forms().filter(|forms| !forms.is_empty())?;
+        Some(&forms[self.ascii_form_index % forms.len()])
+    }
+
+    /// cache key of the art of `pm` in `form`
+    pub fn art_key(&self, pm: &PokemonEntity, form: &str, shiny: bool) -> ArtKey {
+        let name = pm.ascii_name();
+        let root = match self.ascii_form_map.root(&name) {
+            Some(root) => root.clone(),
+            None => self.ascii_root.clone(),
+        };
+
+        ArtKey {
+            name,
+            form: form.to_string(),
+            shiny,
+            root,
+        }
+    }
+
+    /// cache key of the shown form
+    pub fn current_art_key(&self, shiny: bool) -> Option<ArtKey> {
+        let profile = self.profile_with_region_form()?;
+        let form = self.ascii_form().unwrap_or("regular");
+        Some(self.art_key(&profile, form, shiny))
+    }
+
+    /// art file of the shown form, in `t` colors
+    pub fn ascii_path(&self, t: AsciiType) -> Option<PathBuf> {
+        let key = self.current_art_key(matches!(t, AsciiType::Shiny))?;
+        Some(key.path())
+    }
+
+    /// art file of the shown form, shiny when toggled on and there is such art
+    pub fn current_ascii_path(&self) -> Option<PathBuf> {
+        if self.shiny {
+            let shiny = self.current_art_key(true);
+            if shiny.as_ref().is_some_and(|key| self.art_cache.exists(key)) {
+                return shiny.map(|key| key.path());
+            }
+        }
+
+        self.ascii_path(AsciiType::Normal)
+    }
+
+    /// parsed art of the shown form, from the cache
+    pub fn art(&mut self) -> Option<Rc<Text<'static>>> {
+        if self.shiny {
+            let key = self.current_art_key(true)?;
+            if let Some(art) = self.art_cache.get(&key) {
+                return Some(art);
+            }
+        }
+
+        let key = self.current_art_key(false)?;
+        self.art_cache.get(&key)
+    }
+
+    /// sprite of the shown form when sprites are on, shiny like the art
+    pub fn sprite(&mut self) -> Option<Rc<SpriteImage>> {
+        let shiny = self.current_art_key(true)?;
+        let regular = self.current_art_key(false)?;
+        let sprites = self.sprites.as_mut().filter(|sprites| sprites.enabled)?;
+
+        if self.shiny && sprites.exists(&shiny) {
+            return sprites.get(&shiny);
+        }
+        sprites.get(&regular)
+    }
+
+    /// read the arts of the pokemon next to the selected one, while idle
+    pub fn prefetch_art(&mut self) {
+        let Some(selected) = self.list_state.selected() else {
+            return;
+        };
+
+        let start = selected.saturating_sub(PREFETCH_DISTANCE);
+        let keys = self
+            .list_items()
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(PREFETCH_DISTANCE * 2 + 1)
+            .filter(|(i, _)| *i != selected)
+            .map(|(_, pm)| self.art_key(pm, "regular", self.shiny))
+            .collect::<Vec<_>>();
+
+        for key in keys.iter() {
+            self.art_cache.prefetch(key);
+        }
+    }
+
+    pub fn toggle_shiny(&mut self) {
+        self.shiny = !self.shiny;
+    }
+
+    /// turn shiny on or off, returns false if the shown form has no shiny art
+    pub fn set_shiny(&mut self, shiny: bool) -> bool {
+        self.shiny = shiny;
+        !shiny
+            || self
+                .current_art_key(true)
+                .is_some_and(|key| self.art_cache.exists(&key))
+    }
+
+    /// regular art of every form that is neither on disk nor in the archive
+    pub fn missing_art(&self) -> Vec<ArtKey> {
+        let mut missing = vec![];
+        for pm in self.bundle.pokemon.iter() {
+            let region_forms = pm.region_form().unwrap_or_default();
+            for pm in std::iter::once(pm.as_ref()).chain(region_forms.iter()) {
+                let forms = match self.ascii_form_map.get(&pm.ascii_name()) {
+                    Some(forms) if !forms.is_empty() => forms.clone(),
+                    _ => vec!["regular".to_string()],
+                };
+                for form in forms.iter() {
+                    let key = self.art_key(pm, form, false);
+                    if !self.art_cache.exists(&key) {
+                        missing.push(key);
+                    }
+                }
+            }
+        }
+        missing
+    }
+
+    pub fn regional_dex(&self) -> Option<&RegionalDex> {
+        self.bundle.regional.get(self.dex_index.checked_sub(1)?)
+    }
+
+    /// number of pokemon in the current dex
+    pub fn dex_no(&self, pm: &PokemonEntity) -> u16 {
+        self.dex_no.get(&pm.no).copied().unwrap_or(pm.no)
+    }
+
+    pub fn next_regional_dex(&mut self) {
+        self.dex_index = (self.dex_index + 1) % (self.bundle.regional.len() + 1);
+        self.load_pokedex();
+        self.set_list_filter(self.filter_query.clone());
+    }
+
+    /// sort the list and keep the selected pokemon selected
+    pub fn set_sort(&mut self, sort: SortKey) {
+        let selected = self.profile();
+
+        self.sort = sort;
+        self.load_pokedex();
+        self.set_list_filter(self.filter_query.clone());
+
+        if let Some(index) = selected.and_then(|pm| self.position(pm.no)) {
+            self.select(index);
+        }
+    }
+
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+
+        // name order differs between languages, the query is run again on the new list
+        self.set_sort(self.sort);
+    }
+
+    /// index of a pokemon in the visible list
+    pub fn position(&self, no: u16) -> Option<usize> {
+        self.list_items().iter().position(|pm| pm.no == no)
+    }
+
+    fn load_pokedex(&mut self) {
+        self.pokedex = match self.regional_dex() {
+            Some(dex) => dex
+                .pokemon
+                .iter()
+                .filter_map(|no| self.bundle.get_by_no(*no))
+                .collect(),
+            None => self.bundle.pokemon.clone(),
+        };
+
+        self.dex_no = match self.regional_dex() {
+            Some(dex) => dex
+                .pokemon
+                .iter()
+                .enumerate()
+                .map(|(i, no)| (*no, i as u16 + 1))
+                .collect(),
+            None => HashMap::new(),
+        };
+
+        self.sort.sort(&mut self.pokedex, self.locale);
+    }
+
+    pub fn dex_version_len(&self) -> usize {
+        self.profile()
+            .and_then(|profile| self.bundle.dex.get(&profile.no).map(|e| e.len()))
+            .unwrap_or(0)
+    }
+
+    pub fn next_dex_version(&mut self) {
+        let len = self.dex_version_len();
+        if len > 0 {
+            self.dex_version_index = (self.dex_version_index + 1) % len;
+        }
+        self.dex_scrollbar_state.reset();
+    }
+
+    pub fn previous_dex_version(&mut self) {
+        let len = self.dex_version_len();
+        if len > 0 {
+            self.dex_version_index = (self.dex_version_index + len - 1) % len;
+        }
+        self.dex_scrollbar_state.reset();
+    }
+
+    pub fn reset_dex_version(&mut self) {
+        self.dex_version_index = 0;
+        self.dex_scrollbar_state.reset();
+    }
 }
 
 #[derive(Default)]
@@ -260,9 +544,11 @@ pub struct ScrollableParagraphState {
 }
 
 impl ScrollableParagraphState {
+    /// the position is clamped, the height changes with the size of the terminal
     pub fn set_height(&mut self, height: usize) {
         self.scrollbar_state = self.scrollbar_state.content_length(height);
         self.height = height;
+        self.scroll_to(self.position);
     }
 
     pub fn reset(&mut self) {
@@ -283,7 +569,43 @@ impl ScrollableParagraphState {
         self.update_scrollbar();
     }
 
+    pub fn scroll_to(&mut self, position: usize) {
+        self.position = position.min(self.height.saturating_sub(2));
+        self.update_scrollbar();
+    }
+
     fn update_scrollbar(&mut self) {
         self.scrollbar_state = self.scrollbar_state.position(self.position)
     }
 }
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ScrollTarget {
+    List,
+    Ability,
+    Dex,
+}
+
+/// where the last frame drew the widgets the mouse can use
+#[derive(Default, Clone, Copy)]
+pub struct MouseState {
+    /// rows of list items
+    pub list: Rect,
+    pub list_scrollbar: Rect,
+    /// `<- n / m ->` of region forms
+    pub navigation: Rect,
+    pub ability: Rect,
+    pub dex: Rect,
+    /// scrollbar being dragged
+    pub dragging: Option<ScrollTarget>,
+}
+
+impl MouseState {
+    /// widgets that are not drawn again must not take clicks
+    pub fn reset_areas(&mut self) {
+        *self = Self {
+            dragging: self.dragging,
+            ..Default::default()
+        };
+    }
+}
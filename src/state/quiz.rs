@@ -0,0 +1,170 @@
+use std::{path::PathBuf, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+use tui_input::Input;
+
+use crate::pokemon::{romaji, PokemonEntity};
+
+use super::record::{load_record, save_record};
+
+/// wrong guesses allowed before the answer is revealed
+pub const QUIZ_TRIES: u8 = 3;
+
+/// best results, kept across runs
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Debug)]
+pub struct QuizRecord {
+    pub high_score: u32,
+    pub best_streak: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum QuizOutcome {
+    /// points scored
+    Correct(u32),
+    /// tries left
+    Wrong(u8),
+    /// given up or out of tries
+    Revealed,
+}
+
+#[derive(Default)]
+pub struct QuizState {
+    pub current: Option<Rc<PokemonEntity>>,
+    pub input: Input,
+    /// generations to pick from, all when empty
+    pub generations: Vec<u8>,
+    pub score: u32,
+    pub streak: u32,
+    pub tries: u8,
+    pub outcome: Option<QuizOutcome>,
+    pub record: QuizRecord,
+    /// where the record is saved, not saved when `None`
+    path: Option<PathBuf>,
+    rng: fastrand::Rng,
+}
+
+impl QuizState {
+    /// load the record from `path`
+    pub fn path(mut self, path: PathBuf) -> Self {
+        self.record = load_record(&path);
+        self.path = Some(path);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = fastrand::Rng::with_seed(seed);
+        self
+    }
+
+    /// a question is answered and waits for the next one
+    pub fn is_answered(&self) -> bool {
+        matches!(
+            self.outcome,
+            Some(QuizOutcome::Correct(_) | QuizOutcome::Revealed)
+        )
+    }
+
+    /// pokemon of `pool` in the selected generations
+    pub fn candidates<'a>(&self, pool: &'a [Rc<PokemonEntity>]) -> Vec<&'a Rc<PokemonEntity>> {
+        pool.iter()
+            .filter(|pm| self.generations.is_empty() || self.generations.contains(&pm.generation()))
+            .collect()
+    }
+
+    /// ask a random pokemon of `pool`, returns false if no pokemon is in the selected generations
+    pub fn next(&mut self, pool: &[Rc<PokemonEntity>]) -> bool {
+        let candidates = self.candidates(pool);
+        if candidates.is_empty() {
+            return false;
+        }
+
+        let pm = candidates[self.rng.usize(..candidates.len())].clone();
+        self.ask(pm);
+        true
+    }
+
+    pub fn ask(&mut self, pm: Rc<PokemonEntity>) {
+        self.current = Some(pm);
+        self.input.reset();
+        self.tries = 0;
+        self.outcome = None;
+    }
+
+    /// check the typed name, every language and the romaji of the japanese name are accepted
+    pub fn guess(&mut self) -> Option<QuizOutcome> {
+        let pm = self.current.as_ref()?;
+        let guess = self.input.value();
+        if guess.trim().is_empty() || self.is_answered() {
+            return None;
+        }
+
+        let outcome = if is_close(guess, pm) {
+            self.streak += 1;
+            // 10 points and 2 more for each answer of the streak
+            let points = 10 + 2 * (self.streak - 1);
+            self.score += points;
+            QuizOutcome::Correct(points)
+        } else {
+            self.tries += 1;
+            self.input.reset();
+            if self.tries < QUIZ_TRIES {
+                QuizOutcome::Wrong(QUIZ_TRIES - self.tries)
+            } else {
+                self.streak = 0;
+                QuizOutcome::Revealed
+            }
+        };
+
+        self.outcome = Some(outcome);
+        self.update_record();
+        Some(outcome)
+    }
+
+    pub fn give_up(&mut self) {
+        if self.current.is_some() && !self.is_answered() {
+            self.streak = 0;
+            self.outcome = Some(QuizOutcome::Revealed);
+        }
+    }
+
+    fn update_record(&mut self) {
+        let record = QuizRecord {
+            high_score: self.record.high_score.max(self.score),
+            best_streak: self.record.best_streak.max(self.streak),
+        };
+
+        if record != self.record {
+            self.record = record;
+            save_record(self.path.as_deref(), &self.record);
+        }
+    }
+}
+
+/// names are compared without case, spaces and punctuation, and long names may have typos
+fn is_close(guess: &str, pm: &PokemonEntity) -> bool {
+    let guess = normalize(guess);
+    let romaji = pm.name.text("ja").map(romaji);
+
+    pm.name
+        .values()
+        .map(normalize)
+        .chain(romaji.as_deref().map(normalize))
+        .any(|name| {
+            // one typo for 4 letters, at most 2
+            let allowed = (name.chars().count() / 4).min(2);
+            strsim::levenshtein(&guess, &name) <= allowed
+        })
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '.' | '\'' | '’' | ':' | '：' | '・'))
+        .map(|c| match c {
+            'é' | 'É' => 'e',
+            // hiragana as katakana
+            'ぁ'..='ゖ' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+            c => c,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
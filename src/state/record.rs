@@ -0,0 +1,26 @@
+use std::{fs, io, path::Path};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// record read from `path`, a missing or broken file starts a new one
+pub fn load_record<T: DeserializeOwned + Default>(path: &Path) -> T {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// write `record` to `path` as json, nothing is saved when `path` is `None`
+pub fn save_record<T: Serialize>(path: Option<&Path>, record: &T) {
+    if let Some(path) = path {
+        // the quiz and the trainer go on even if the record can not be saved
+        let _ = write_record(path, record);
+    }
+}
+
+fn write_record<T: Serialize>(path: &Path, record: &T) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string(record)?)
+}
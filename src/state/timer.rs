@@ -0,0 +1,68 @@
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Timer {
+    /// hide the message of the last command
+    Message,
+}
+
+impl Timer {
+    pub fn duration(self) -> Duration {
+        match self {
+            Timer::Message => Duration::from_secs(3),
+        }
+    }
+}
+
+/// timers started by the app, fired by the event loop
+#[derive(Default)]
+pub struct Timers {
+    /// time of the event being handled
+    now: Option<Instant>,
+    deadlines: Vec<(Instant, Timer)>,
+}
+
+impl Timers {
+    pub fn set_now(&mut self, now: Instant) {
+        self.now = Some(now);
+    }
+
+    fn now(&self) -> Instant {
+        self.now.unwrap_or_else(Instant::now)
+    }
+
+    /// start or restart a timer
+    pub fn start(&mut self, timer: Timer) {
+        self.cancel(timer);
+        self.deadlines.push((self.now() + timer.duration(), timer));
+    }
+
+    pub fn cancel(&mut self, timer: Timer) {
+        self.deadlines.retain(|(_, t)| *t != timer);
+    }
+
+    pub fn is_running(&self, timer: Timer) -> bool {
+        self.deadlines.iter().any(|(_, t)| *t == timer)
+    }
+
+    /// remove and return the timers that are due
+    pub fn expired(&mut self) -> Vec<Timer> {
+        let now = self.now();
+        let (expired, running) = self
+            .deadlines
+            .drain(..)
+            .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+        self.deadlines = running;
+
+        expired.into_iter().map(|(_, timer)| timer).collect()
+    }
+
+    /// time until the next timer is due
+    pub fn timeout(&self) -> Option<Duration> {
+        let now = self.now();
+        self.deadlines
+            .iter()
+            .map(|(deadline, _)| deadline.saturating_duration_since(now))
+            .min()
+    }
+}
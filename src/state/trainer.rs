@@ -0,0 +1,259 @@
+use std::{collections::HashMap, path::PathBuf, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::pokemon::{Effectiveness, PokemonEntity, PokemonType, PokemonTypeKind};
+
+use super::record::{load_record, save_record};
+
+/// correct answers in a row before a missed matchup leaves the reviews
+pub const REVIEW_LEVELS: u8 = 4;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub struct Matchup {
+    pub attack: PokemonTypeKind,
+    pub defense: PokemonType,
+}
+
+impl Matchup {
+    pub fn effectiveness(&self) -> Effectiveness {
+        self.defense.damage_from(self.attack)
+    }
+}
+
+/// a matchup, asked against a real pokemon when `species` is some
+pub struct TypeQuestion {
+    pub matchup: Matchup,
+    pub species: Option<Rc<PokemonEntity>>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq, Clone, Copy)]
+pub struct Accuracy {
+    pub right: u32,
+    pub total: u32,
+}
+
+impl Accuracy {
+    /// percentage of right answers
+    pub fn percent(&self) -> u32 {
+        (self.right * 100).checked_div(self.total).unwrap_or(0)
+    }
+}
+
+/// a missed matchup, asked again after `2 << level` questions
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub struct Review {
+    pub matchup: Matchup,
+    pub level: u8,
+    /// asked again once this many questions are answered
+    pub due: u32,
+}
+
+/// answers of all runs
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct TrainerRecord {
+    pub answered: u32,
+    /// accuracy of the questions each type is in, attacking or defending
+    pub types: HashMap<PokemonTypeKind, Accuracy>,
+    pub reviews: Vec<Review>,
+}
+
+impl TrainerRecord {
+    pub fn accuracy(&self) -> Accuracy {
+        let right = self.types.values().map(|a| a.right).sum::<u32>();
+        let total = self.types.values().map(|a| a.total).sum::<u32>();
+        Accuracy { right, total }
+    }
+
+    /// types with the lowest accuracy first
+    pub fn weakest(&self, n: usize) -> Vec<(PokemonTypeKind, Accuracy)> {
+        let mut types = self
+            .types
+            .iter()
+            .filter(|(_, a)| a.right < a.total)
+            .map(|(t, a)| (*t, *a))
+            .collect::<Vec<_>>();
+        types.sort_by_key(|(t, a)| {
+            (
+                a.percent(),
+                PokemonTypeKind::ALL.iter().position(|k| k == t),
+            )
+        });
+        types.truncate(n);
+        types
+    }
+
+    pub fn due_reviews(&self) -> usize {
+        self.reviews
+            .iter()
+            .filter(|r| r.due <= self.answered)
+            .count()
+    }
+
+    fn review(&mut self, matchup: Matchup, correct: bool) {
+        let answered = self.answered;
+        let index = self.reviews.iter().position(|r| r.matchup == matchup);
+
+        match (index, correct) {
+            (Some(i), true) => {
+                let review = &mut self.reviews[i];
+                review.level += 1;
+                review.due = answered + (2 << review.level);
+                if review.level >= REVIEW_LEVELS {
+                    self.reviews.remove(i);
+                }
+            }
+            (None, true) => (),
+            (Some(i), false) => {
+                self.reviews[i].level = 0;
+                self.reviews[i].due = answered + 2;
+            }
+            (None, false) => self.reviews.push(Review {
+                matchup,
+                level: 0,
+                due: answered + 2,
+            }),
+        }
+    }
+}
+
+/// type effectiveness trainer
+#[derive(Default)]
+pub struct TrainerState {
+    pub question: Option<TypeQuestion>,
+    pub answer: Option<Effectiveness>,
+    pub record: TrainerRecord,
+    /// where the record is saved, not saved when `None`
+    path: Option<PathBuf>,
+    rng: fastrand::Rng,
+}
+
+impl TrainerState {
+    /// load the record from `path`
+    pub fn path(mut self, path: PathBuf) -> Self {
+        self.record = load_record(&path);
+        self.path = Some(path);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = fastrand::Rng::with_seed(seed);
+        self
+    }
+
+    pub fn is_answered(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    pub fn is_correct(&self) -> bool {
+        match (&self.question, self.answer) {
+            (Some(question), Some(answer)) => question.matchup.effectiveness() == answer,
+            _ => false,
+        }
+    }
+
+    /// the most overdue review, or a new matchup leaning to the types often missed
+    pub fn next(&mut self, pool: &[Rc<PokemonEntity>]) {
+        let review = self
+            .record
+            .reviews
+            .iter()
+            .filter(|r| r.due <= self.record.answered)
+            .min_by_key(|r| r.due)
+            .map(|r| r.matchup);
+
+        let question = match review {
+            Some(matchup) => {
+                // a pokemon of the types if there is one
+                let species = pool.iter().filter(|pm| pm.r#type == matchup.defense);
+                let species = species.collect::<Vec<_>>();
+                TypeQuestion {
+                    matchup,
+                    species: self.rng.choice(species).cloned(),
+                }
+            }
+            None => self.random_question(pool),
+        };
+
+        self.ask(question);
+    }
+
+    pub fn ask(&mut self, question: TypeQuestion) {
+        self.question = Some(question);
+        self.answer = None;
+    }
+
+    fn random_question(&mut self, pool: &[Rc<PokemonEntity>]) -> TypeQuestion {
+        // 1 to 5 times as likely as the share of misses grows
+        let weights = PokemonTypeKind::ALL.map(|t| {
+            let accuracy = self.record.types.get(&t).copied().unwrap_or_default();
+            1 + (accuracy.total - accuracy.right) * 4 / accuracy.total.max(1)
+        });
+        let mut pick = self.rng.u32(..weights.iter().sum::<u32>());
+        let attack = PokemonTypeKind::ALL
+            .into_iter()
+            .zip(weights)
+            .find(|(_, weight)| {
+                let found = pick < *weight;
+                pick = pick.saturating_sub(*weight);
+                found
+            })
+            .map_or(PokemonTypeKind::Normal, |(t, _)| t);
+
+        if self.rng.bool() {
+            if let Some(pm) = self.rng.choice(pool) {
+                return TypeQuestion {
+                    matchup: Matchup {
+                        attack,
+                        defense: pm.r#type,
+                    },
+                    species: Some(pm.clone()),
+                };
+            }
+        }
+
+        let first = PokemonTypeKind::ALL[self.rng.usize(..PokemonTypeKind::ALL.len())];
+        let second = PokemonTypeKind::ALL[self.rng.usize(..PokemonTypeKind::ALL.len())];
+        TypeQuestion {
+            matchup: Matchup {
+                attack,
+                defense: PokemonType(first, (second != first).then_some(second)),
+            },
+            species: None,
+        }
+    }
+
+    /// answer the question, returns whether it is right
+    pub fn answer(&mut self, answer: Effectiveness) -> Option<bool> {
+        let matchup = self.question.as_ref()?.matchup;
+        if self.is_answered() {
+            return None;
+        }
+
+        self.answer = Some(answer);
+        let correct = self.is_correct();
+
+        let mut types = vec![];
+        for t in [
+            Some(matchup.attack),
+            Some(matchup.defense.0),
+            matchup.defense.1,
+        ] {
+            match t {
+                Some(t) if !types.contains(&t) => types.push(t),
+                _ => (),
+            }
+        }
+        for t in types {
+            let accuracy = self.record.types.entry(t).or_default();
+            accuracy.total += 1;
+            accuracy.right += correct as u32;
+        }
+
+        self.record.answered += 1;
+        self.record.review(matchup, correct);
+
+        save_record(self.path.as_deref(), &self.record);
+        Some(correct)
+    }
+}
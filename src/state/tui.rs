@@ -1,12 +1,44 @@
 use super::InputMode;
 
+/// panes that take focus, in the order of tab
+#[derive(Default, Copy, Clone, PartialEq, Debug)]
+pub enum Pane {
+    #[default]
+    List,
+    Art,
+    Stats,
+    Info,
+    Dex,
+    Abilities,
+}
+
+impl Pane {
+    pub const ALL: [Pane; 6] = [
+        Pane::List,
+        Pane::Art,
+        Pane::Stats,
+        Pane::Info,
+        Pane::Dex,
+        Pane::Abilities,
+    ];
+}
+
 #[derive(Default, Copy, Clone)]
 pub struct TuiState {
     pub show_help: bool,
     pub show_list: bool,
     pub show_abilities: bool,
     pub show_iv: bool,
+    pub show_info: bool,
+    pub show_dex: bool,
+    /// first alt name as a second column of the list
+    pub show_list_column: bool,
+    pub focus: Pane,
     pub cursor: Option<(u16, u16)>,
+    /// a popup is drawn over the profile, which leaves out its sprite
+    pub popup: bool,
+    /// the species has dex entries, the dex pane is left out without them
+    pub has_dex: bool,
     pub input_mode: InputMode,
 }
 
@@ -16,6 +48,8 @@ impl TuiState {
             show_list: true,
             show_abilities: true,
             show_iv: true,
+            show_info: true,
+            show_dex: true,
             ..Default::default()
         }
     }
@@ -35,4 +69,62 @@ impl TuiState {
     pub fn toggle_show_iv(&mut self) {
         self.show_iv = !self.show_iv;
     }
+
+    pub fn toggle_show_info(&mut self) {
+        self.show_info = !self.show_info;
+    }
+
+    pub fn toggle_show_dex(&mut self) {
+        self.show_dex = !self.show_dex;
+    }
+
+    pub fn toggle_show_list_column(&mut self) {
+        self.show_list_column = !self.show_list_column;
+    }
+
+    pub fn is_visible(&self, pane: Pane) -> bool {
+        match pane {
+            Pane::List => self.show_list,
+            Pane::Art => true,
+            Pane::Stats => self.show_iv,
+            Pane::Info => self.show_info,
+            Pane::Dex => self.show_dex && self.has_dex,
+            Pane::Abilities => self.show_abilities,
+        }
+    }
+
+    /// focused pane, hidden panes pass the focus to the list or the art
+    pub fn focused(&self) -> Pane {
+        if self.is_visible(self.focus) {
+            self.focus
+        } else if self.show_list {
+            Pane::List
+        } else {
+            Pane::Art
+        }
+    }
+
+    pub fn is_focused(&self, pane: Pane) -> bool {
+        self.focused() == pane
+    }
+
+    /// move the focus to the next visible pane
+    pub fn focus_next(&mut self, backward: bool) {
+        let len = Pane::ALL.len();
+        let index = Pane::ALL
+            .iter()
+            .position(|p| *p == self.focused())
+            .unwrap_or(0);
+
+        self.focus = (1..len)
+            .map(|step| {
+                if backward {
+                    Pane::ALL[(index + len - step) % len]
+                } else {
+                    Pane::ALL[(index + step) % len]
+                }
+            })
+            .find(|pane| self.is_visible(*pane))
+            .unwrap_or(self.focused());
+    }
 }
@@ -6,16 +6,26 @@ use ratatui::{
 use crate::{
     state::{AppState, InputMode},
     widget::{
-        filter::Filter, help::Help, pokemon_list::PokemonList, profile::PokemonProfileWidget,
+        breeding::Breeding, compare::Compare, filter::Filter, help::Help,
+        pokemon_list::PokemonList, profile::PokemonProfileWidget, quiz::Quiz, trainer::Trainer,
     },
 };
 
 pub fn ui(f: &mut Frame, app: &mut AppState) {
     let area = f.area();
+    app.pokemon_list.mouse.reset_areas();
+    app.tui.cursor = None;
+    app.tui.popup = app.has_popup();
+    let list = &app.pokemon_list;
+    app.tui.has_dex = list
+        .profile()
+        .is_some_and(|pm| list.bundle.has_dex_text(&pm));
+    // wider list to fit the second column of names
+    let list_width = if app.tui.show_list_column { 45 } else { 25 };
     let constraint = if !app.tui.show_list {
         [Constraint::Percentage(100), Constraint::Length(0)]
     } else if area.width >= 80 {
-        [Constraint::Min(0), Constraint::Length(25)]
+        [Constraint::Min(0), Constraint::Length(list_width)]
     } else if area.width >= 120 {
         [Constraint::Min(0), Constraint::Length(40)]
     } else {
@@ -31,8 +41,8 @@ pub fn ui(f: &mut Frame, app: &mut AppState) {
     if right.width >= 25 {
         let [search, pm_list] = Layout::vertical([
             Constraint::Length(match app.tui.input_mode {
-                InputMode::Normal => 1,
-                InputMode::Editing => 3,
+                InputMode::Normal | InputMode::Quiz | InputMode::Trainer => 1,
+                InputMode::Editing | InputMode::Command => 3,
             }),
             Constraint::Min(0),
         ])
@@ -42,18 +52,38 @@ pub fn ui(f: &mut Frame, app: &mut AppState) {
         f.render_stateful_widget(Filter, search, app);
 
         // pm list
-        f.render_stateful_widget(PokemonList, pm_list, &mut app.pokemon_list);
+        f.render_stateful_widget(PokemonList(app.tui), pm_list, &mut app.pokemon_list);
+    }
+
+    if app.breeding.is_ready() {
+        let area = centered_rect(60, 70, area);
+        f.render_stateful_widget(Breeding, area, app);
+    }
+
+    if app.compare.is_ready() {
+        let area = centered_rect(60, 70, area);
+        f.render_stateful_widget(Compare, area, app);
+    }
 
-        // search input cursor
-        if let Some((x, y)) = app.tui.cursor {
-            f.set_cursor_position((x, y))
-        };
+    if matches!(app.tui.input_mode, InputMode::Quiz) {
+        let area = centered_rect(70, 80, area);
+        f.render_stateful_widget(Quiz, area, app);
+    }
+
+    if matches!(app.tui.input_mode, InputMode::Trainer) {
+        let area = centered_rect(70, 60, area);
+        f.render_stateful_widget(Trainer, area, app);
     }
 
     if app.tui.show_help {
         let area = centered_rect(50, 70, area);
-        f.render_widget(Help, area);
+        f.render_widget(Help(app.locale()), area);
     }
+
+    // cursor of the search box, command line or quiz answer
+    if let Some((x, y)) = app.tui.cursor {
+        f.set_cursor_position((x, y))
+    };
 }
 
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
@@ -0,0 +1,115 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{
+        Block, Clear, Paragraph, Scrollbar, ScrollbarOrientation, StatefulWidget, Widget, Wrap,
+    },
+};
+
+use crate::{i18n::tr, pokemon::BreedingResult, state::AppState};
+
+pub struct Breeding;
+
+impl StatefulWidget for Breeding {
+    type State = AppState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let (Some(mother), Some(father)) = (&state.breeding.mother, &state.breeding.father) else {
+            return;
+        };
+
+        Clear.render(area, buf);
+
+        let bundle = &state.pokemon_list.bundle;
+        let locale = state.pokemon_list.locale;
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+
+        let mut lines = vec![
+            Line::from(format!(
+                "{} (♀) x {} (♂)",
+                mother.name_with_no(locale),
+                father.name_with_no(locale)
+            )),
+            Line::from(""),
+        ];
+
+        match bundle.check_breeding(mother, father) {
+            BreedingResult::Compatible {
+                offspring,
+                egg_moves,
+            } => {
+                lines.push(Line::styled(
+                    tr(locale, "breeding.compatible"),
+                    bold.fg(Color::Green),
+                ));
+                lines.push(Line::from(format!(
+                    "{} {}",
+                    tr(locale, "breeding.offspring"),
+                    offspring.name_with_no(locale)
+                )));
+                lines.push(Line::from(format!(
+                    "{} {}",
+                    tr(locale, "breeding.egg_moves"),
+                    if egg_moves.is_empty() {
+                        String::from("-")
+                    } else {
+                        egg_moves.join(", ")
+                    }
+                )));
+            }
+            BreedingResult::Incompatible(issue) => {
+                lines.push(Line::styled(
+                    tr(locale, "breeding.incompatible"),
+                    bold.fg(Color::Red),
+                ));
+                lines.push(Line::from(issue.reason(locale)));
+            }
+        }
+
+        for group in mother.species.egg_group.iter() {
+            let members = bundle
+                .egg_group_members(*group)
+                .iter()
+                .map(|pm| pm.name.get(locale))
+                .collect::<Vec<_>>();
+
+            lines.push(Line::from(""));
+            lines.push(Line::styled(
+                format!(
+                    "{} {} ({})",
+                    tr(locale, "breeding.egg_group"),
+                    group,
+                    members.len()
+                ),
+                bold,
+            ));
+            lines.push(Line::from(members.join(", ")));
+        }
+
+        // count wrapped lines for the scrollbar
+        let width = (area.width as usize).saturating_sub(2).max(1);
+        let count = lines
+            .iter()
+            .map(|l| l.width().div_ceil(width).max(1))
+            .sum::<usize>();
+
+        let scroll = &mut state.breeding.scroll;
+        scroll.set_height(if count > (area.height as usize).saturating_sub(2) {
+            count
+        } else {
+            0
+        });
+
+        Paragraph::new(lines)
+            .block(Block::bordered().title(tr(locale, "breeding.title")))
+            .wrap(Wrap { trim: false })
+            .scroll((scroll.position as u16, 0))
+            .render(area, buf);
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .style(Style::default().bg(Color::DarkGray))
+            .render(area, buf, &mut scroll.scrollbar_state);
+    }
+}
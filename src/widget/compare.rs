@@ -0,0 +1,111 @@
+use std::cmp::Ordering;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::{
+    i18n::tr,
+    pokemon::{Locale, PokemonEntity, PokemonIV},
+    state::AppState,
+};
+
+pub struct Compare;
+
+impl Compare {
+    fn stats(iv: &PokemonIV) -> [(&'static str, u16); 7] {
+        [
+            ("stat.hp", iv.hp),
+            ("stat.atk", iv.att),
+            ("stat.def", iv.def),
+            ("stat.s_atk", iv.s_att),
+            ("stat.s_def", iv.s_def),
+            ("stat.spd", iv.spd),
+            (
+                "stat.total",
+                iv.hp + iv.att + iv.def + iv.s_att + iv.s_def + iv.spd,
+            ),
+        ]
+    }
+
+    fn type_text(pm: &PokemonEntity, locale: Locale) -> String {
+        match pm.r#type.1 {
+            Some(second) => format!("{} / {}", pm.r#type.0.name(locale), second.name(locale)),
+            None => pm.r#type.0.name(locale).to_string(),
+        }
+    }
+
+    /// pad `title` to `width` columns, titles may be double width
+    fn pad(title: &str, width: usize) -> String {
+        let padding = width.saturating_sub(Line::from(title).width());
+        format!("{}{}", title, " ".repeat(padding))
+    }
+}
+
+impl StatefulWidget for Compare {
+    type State = AppState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let (Some(left), Some(right)) = (&state.compare.left, &state.compare.right) else {
+            return;
+        };
+
+        Clear.render(area, buf);
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let locale = state.pokemon_list.locale;
+
+        let t = |key| tr(locale, key);
+        let width = Self::stats(&left.iv)
+            .iter()
+            .map(|(key, _)| t(key))
+            .chain([t("compare.type")])
+            .map(|title| Line::from(title).width() + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut lines = vec![
+            Line::styled(
+                format!(
+                    "{} vs {}",
+                    left.name_with_no(locale),
+                    right.name_with_no(locale)
+                ),
+                bold,
+            ),
+            Line::from(""),
+            Line::from(format!(
+                "{}{} vs {}",
+                Self::pad(t("compare.type"), width),
+                Self::type_text(left, locale),
+                Self::type_text(right, locale)
+            )),
+            Line::from(""),
+        ];
+
+        let stats = Self::stats(&left.iv)
+            .into_iter()
+            .zip(Self::stats(&right.iv));
+
+        for ((key, l), (_, r)) in stats {
+            let (diff, color) = match r.cmp(&l) {
+                Ordering::Greater => (format!("+{}", r - l), Color::Green),
+                Ordering::Less => (format!("-{}", l - r), Color::Red),
+                Ordering::Equal => (String::from("="), Color::Reset),
+            };
+
+            lines.push(Line::from(vec![
+                Span::raw(format!("{}{:>4}  {:>4}  ", Self::pad(t(key), width), l, r)),
+                Span::styled(diff, Style::default().fg(color)),
+            ]));
+        }
+
+        Paragraph::new(lines)
+            .block(Block::bordered().title(t("compare.title")))
+            .render(area, buf);
+    }
+}
@@ -1,12 +1,16 @@
 use ratatui::{
-    layout::{Alignment, Constraint, Layout},
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style},
+    text::Line,
     widgets::{Block, Borders, Paragraph, StatefulWidget, Widget},
 };
+use tui_input::Input;
 
 use crate::{
     env::LIST_H_MARGIN,
-    state::{AppState, InputMode},
+    i18n::{tr, trf},
+    state::{command::CommandMessage, AppState, InputMode},
 };
 
 pub struct Filter;
@@ -20,15 +24,33 @@ impl Filter {
     }
 }
 
+impl Filter {
+    /// render input after `prefix`, returns the cursor position
+    fn render_input(
+        self,
+        area: Rect,
+        wrapper: Rect,
+        buf: &mut Buffer,
+        input: &Input,
+        prefix: &str,
+    ) -> Option<(u16, u16)> {
+        let width = area.width.max(3) - 3;
+        let prefix_width = prefix.chars().count();
+        let scroll = input.visual_scroll((width as usize).saturating_sub(prefix_width));
+        self.paragraph(scroll, &format!("{}{}", prefix, input.value()))
+            .render(wrapper, buf);
+
+        Some((
+            wrapper.x + ((input.visual_cursor() + prefix_width).max(scroll) - scroll) as u16 + 1,
+            wrapper.y + 1,
+        ))
+    }
+}
+
 impl StatefulWidget for Filter {
     type State = AppState;
 
-    fn render(
-        self,
-        area: ratatui::layout::Rect,
-        buf: &mut ratatui::buffer::Buffer,
-        state: &mut Self::State,
-    ) {
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         let [layout] = Layout::default()
             .constraints([Constraint::Min(0)])
             .horizontal_margin(LIST_H_MARGIN)
@@ -42,25 +64,40 @@ impl StatefulWidget for Filter {
         Block::default().borders(Borders::LEFT).render(layout, buf);
 
         match state.tui.input_mode {
-            InputMode::Normal => {
+            InputMode::Normal | InputMode::Quiz | InputMode::Trainer => {
                 state.tui.cursor = None;
+                let locale = state.locale();
+                let title = match (&state.command_line.message, &state.breeding.mother) {
+                    (Some(CommandMessage::Info(message)), _) => Line::from(message.as_str()),
+                    (Some(CommandMessage::Error(message)), _) => {
+                        Line::styled(message.as_str(), Style::default().fg(Color::Red))
+                    }
+                    (None, Some(mother)) if state.breeding.is_picking() => {
+                        let name = mother.name.get(locale);
+                        Line::from(trf(locale, "breeding.picking", &[&name]))
+                    }
+                    (None, _) if state.compare.is_picking() => {
+                        let left = state.compare.left.as_ref().map(|pm| pm.name.get(locale));
+                        let left = left.unwrap_or_default();
+                        Line::from(trf(locale, "compare.picking", &[&left]))
+                    }
+                    _ => Line::from(tr(locale, "search.hint")),
+                };
+
                 Block::default()
                     .title_alignment(Alignment::Center)
-                    .title("Press '/' search")
+                    .title(title)
                     .render(wrapper, buf);
             }
 
             InputMode::Editing => {
-                let width = area.width.max(3) - 3;
-                let scroll = state.key_handle.input.visual_scroll(width as usize);
-                self.paragraph(scroll, state.key_handle.input.value())
-                    .render(wrapper, buf);
-                state.tui.cursor = Some((
-                    wrapper.x
-                        + ((state.key_handle.input.visual_cursor()).max(scroll) - scroll) as u16
-                        + 1,
-                    wrapper.y + 1,
-                ))
+                state.tui.cursor =
+                    self.render_input(area, wrapper, buf, &state.key_handle.input, "");
+            }
+
+            InputMode::Command => {
+                state.tui.cursor =
+                    self.render_input(area, wrapper, buf, &state.command_line.input, ":");
             }
         }
     }
@@ -5,7 +5,9 @@ use ratatui::{
     widgets::{Block, Clear, Paragraph, Widget},
 };
 
-pub struct Help;
+use crate::{i18n::tr, pokemon::Locale};
+
+pub struct Help(pub Locale);
 
 impl Widget for Help {
     fn render(self, area: Rect, buf: &mut Buffer) {
@@ -14,21 +16,9 @@ impl Widget for Help {
         let block = Block::bordered()
             .style(Style::default().bg(Color::Gray))
             .title_alignment(Alignment::Center)
-            .title("Help");
+            .title(tr(self.0, "help.title"));
 
-        let text = "
-Use arrow keys or hjkl to move\n
-Press page up or page down to move 4 lines at a time\n
-Press f to switch ascii forms\n
-Press E to toggle list showing\n
-Press A to toggle ability showing\n
-Press V to toggle iv showing\n
-Press q to exit\n
-Press H for help\n
-Press gg to go to the top and G to go to the bottom\n
-Press alt+j or alt+k to scroll up or down ability description\n
-Use / to enter search mode\n\n\n\n
-Press H to exit help";
+        let text = tr(self.0, "help.text");
 
         Paragraph::new(text)
             .style(Style::default().fg(Color::Black))
@@ -1,4 +1,32 @@
+pub mod breeding;
+pub mod compare;
 pub mod filter;
 pub mod help;
 pub mod pokemon_list;
 pub mod profile;
+pub mod quiz;
+pub mod trainer;
+
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Text},
+};
+
+use crate::{i18n::tr, pokemon::Locale};
+
+/// border of the focused pane
+pub fn border_style(focused: bool) -> Style {
+    if focused {
+        Style::default().fg(Color::LightGreen)
+    } else {
+        Style::default()
+    }
+}
+
+/// shown in place of art that is neither on disk nor in the archive, with the file looked for
+pub fn missing_art(file: Option<String>, locale: Locale) -> Text<'static> {
+    let mut lines = vec![Line::from(tr(locale, "art.missing"))];
+    lines.extend(file.map(Line::from));
+    lines.push(Line::from(tr(locale, "art.doctor")));
+    Text::from(lines).style(Style::default().fg(Color::DarkGray))
+}
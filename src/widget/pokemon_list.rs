@@ -1,13 +1,21 @@
 use ratatui::{
-    layout::{Constraint, Layout},
+    layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::Line,
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Scrollbar, ScrollbarOrientation, StatefulWidget},
 };
 
-use crate::{env::LIST_H_MARGIN, state::PokemonListState};
+use crate::{
+    env::LIST_H_MARGIN,
+    state::{
+        tui::{Pane, TuiState},
+        PokemonListState,
+    },
+};
+
+use super::border_style;
 
-pub struct PokemonList;
+pub struct PokemonList(pub TuiState);
 
 impl StatefulWidget for PokemonList {
     type State = PokemonListState;
@@ -23,14 +31,59 @@ impl StatefulWidget for PokemonList {
             .horizontal_margin(LIST_H_MARGIN)
             .areas(area);
 
-        let items: Vec<ListItem> = state
+        let names: Vec<(String, Option<String>)> = state
             .list_items()
             .iter()
-            .map(|item| ListItem::new(vec![Line::from(item.name_with_no())]))
+            .map(|item| {
+                let alt = if self.0.show_list_column {
+                    state.alt_names_of(item).into_iter().next()
+                } else {
+                    None
+                };
+                (item.name_with_dex_no(state.dex_no(item), state.locale), alt)
+            })
+            .collect();
+
+        // the first alt name is shown as a second column
+        let width = names
+            .iter()
+            .map(|(name, _)| Line::from(name.as_str()).width())
+            .max()
+            .unwrap_or(0);
+
+        let items: Vec<ListItem> = names
+            .into_iter()
+            .map(|(name, alt)| match alt {
+                Some(alt) => {
+                    let padding = width - Line::from(name.as_str()).width() + 2;
+                    ListItem::new(Line::from(vec![
+                        Span::raw(name + &" ".repeat(padding)),
+                        Span::styled(alt, Style::default().fg(Color::DarkGray)),
+                    ]))
+                }
+                None => ListItem::new(Line::from(name)),
+            })
             .collect();
 
+        let block = match state.regional_dex() {
+            Some(dex) => Block::default().borders(Borders::LEFT).title(format!(
+                "{} ({})",
+                dex.name(state.locale),
+                dex.games_name(state.locale)
+            )),
+            None => Block::default().borders(Borders::LEFT),
+        };
+
+        let block = block.border_style(border_style(self.0.is_focused(Pane::List)));
+        state.mouse.list = block.inner(layout);
+        state.mouse.list_scrollbar = Rect {
+            x: layout.right().saturating_sub(1),
+            width: 1,
+            ..layout
+        };
+
         List::new(items)
-            .block(Block::default().borders(Borders::LEFT))
+            .block(block)
             .highlight_style(
                 Style::default()
                     .bg(Color::LightGreen)
@@ -6,16 +6,40 @@ use ratatui::{
     widgets::{Block, Paragraph, Scrollbar, ScrollbarOrientation, StatefulWidget, Widget, Wrap},
 };
 
-use crate::{pokemon::PokemonAbilityText, state::pokemon::ScrollableParagraphState};
+use crate::{
+    i18n::tr,
+    pokemon::{Locale, PokemonAbilityText},
+    state::pokemon::ScrollableParagraphState,
+    widget::border_style,
+};
+
+pub struct AbilityParaGraph {
+    abilities: Vec<PokemonAbilityText>,
+    locale: Locale,
+    focused: bool,
+}
 
-pub struct AbilityParaGraph(pub Vec<PokemonAbilityText>);
+impl AbilityParaGraph {
+    pub fn new(abilities: Vec<PokemonAbilityText>, locale: Locale) -> Self {
+        Self {
+            abilities,
+            locale,
+            focused: false,
+        }
+    }
+
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+}
 
 impl StatefulWidget for AbilityParaGraph {
     type State = ScrollableParagraphState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         let (span, count) =
-            self.0
+            self.abilities
                 .iter()
                 .enumerate()
                 .fold((vec![], 0), |(mut line, mut count), (i, a)| {
@@ -42,7 +66,11 @@ impl StatefulWidget for AbilityParaGraph {
         });
 
         Paragraph::new(span)
-            .block(Block::bordered().title("Ability"))
+            .block(
+                Block::bordered()
+                    .title(tr(self.locale, "ability.title"))
+                    .border_style(border_style(self.focused)),
+            )
             .wrap(Wrap { trim: false })
             .scroll((state.position as u16, 0))
             .render(area, buf);
@@ -53,7 +81,7 @@ impl StatefulWidget for AbilityParaGraph {
     }
 }
 
-fn get_lines(text: &str, width: usize) -> Vec<String> {
+pub(super) fn get_lines(text: &str, width: usize) -> Vec<String> {
     let options = textwrap::Options::new(width).word_separator(textwrap::WordSeparator::AsciiSpace);
 
     let lines = textwrap::wrap(text, &options);
@@ -0,0 +1,94 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Paragraph, Scrollbar, ScrollbarOrientation, StatefulWidget, Widget},
+};
+
+use crate::{
+    i18n::tr,
+    pokemon::{Locale, PokemonDexText},
+    state::pokemon::ScrollableParagraphState,
+    widget::border_style,
+};
+
+use super::ability::get_lines;
+
+pub struct DexParagraph {
+    entries: Vec<PokemonDexText>,
+    index: usize,
+    locale: Locale,
+    focused: bool,
+}
+
+impl DexParagraph {
+    pub const HEIGHT: u16 = 6;
+
+    pub fn new(entries: Vec<PokemonDexText>, index: usize, locale: Locale) -> Self {
+        Self {
+            entries,
+            index,
+            locale,
+            focused: false,
+        }
+    }
+
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+}
+
+impl StatefulWidget for DexParagraph {
+    type State = ScrollableParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let len = self.entries.len();
+        let Some(entry) = self.entries.get(self.index % len.max(1)) else {
+            state.set_height(0);
+            Paragraph::new("-")
+                .block(
+                    Block::bordered()
+                        .title(tr(self.locale, "dex.title"))
+                        .border_style(border_style(self.focused)),
+                )
+                .render(area, buf);
+            return;
+        };
+
+        let lines: Vec<Line> = get_lines(&entry.text, (area.width as usize).saturating_sub(2))
+            .into_iter()
+            .map(Line::from)
+            .collect();
+
+        let count = lines.len();
+        let layout_height = area.height as usize;
+        state.set_height(if count > layout_height.saturating_sub(2) {
+            count
+        } else {
+            0
+        });
+
+        let title = format!(
+            "{} - {} <- {} / {} ->",
+            tr(self.locale, "dex.title"),
+            entry.version,
+            self.index % len + 1,
+            len
+        );
+
+        Paragraph::new(lines)
+            .block(
+                Block::bordered()
+                    .title(title)
+                    .border_style(border_style(self.focused)),
+            )
+            .scroll((state.position as u16, 0))
+            .render(area, buf);
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .style(Style::default().bg(Color::DarkGray))
+            .render(area, buf, &mut state.scrollbar_state);
+    }
+}
@@ -0,0 +1,96 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::Line,
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::{
+    i18n::tr,
+    pokemon::{Locale, PokemonSpecies},
+    widget::border_style,
+};
+
+pub struct SpeciesInfo<'a> {
+    species: &'a PokemonSpecies,
+    generation: u8,
+    locale: Locale,
+    focused: bool,
+}
+
+impl<'a> SpeciesInfo<'a> {
+    // 5 lines of content with border
+    pub const HEIGHT: u16 = 7;
+
+    pub fn new(species: &'a PokemonSpecies, generation: u8, locale: Locale) -> Self {
+        Self {
+            species,
+            generation,
+            locale,
+            focused: false,
+        }
+    }
+
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    fn or_unknown<T: ToString>(value: Option<T>) -> String {
+        value.map_or_else(|| String::from("-"), |v| v.to_string())
+    }
+}
+
+impl Widget for SpeciesInfo<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let s = self.species;
+
+        let egg_group = if s.egg_group.is_empty() {
+            String::from("-")
+        } else {
+            s.egg_group_text()
+        };
+
+        let t = |key| tr(self.locale, key);
+
+        let lines = vec![
+            Line::from(format!(
+                "{} {}  {} {}",
+                t("info.generation"),
+                self.generation,
+                t("info.category"),
+                Self::or_unknown(s.category.as_ref().map(|c| c.get(self.locale)))
+            )),
+            Line::from(format!(
+                "{} {} m  {} {} kg",
+                t("info.height"),
+                Self::or_unknown(s.height),
+                t("info.weight"),
+                Self::or_unknown(s.weight)
+            )),
+            Line::from(format!("{} {}", t("info.egg_group"), egg_group)),
+            Line::from(format!(
+                "{} {}  {} {}",
+                t("info.catch_rate"),
+                Self::or_unknown(s.catch_rate),
+                t("info.base_exp"),
+                Self::or_unknown(s.base_exp)
+            )),
+            Line::from(format!(
+                "{} {}  {} {}",
+                t("info.growth"),
+                Self::or_unknown(s.growth_rate),
+                t("info.friendship"),
+                Self::or_unknown(s.friendship)
+            )),
+        ];
+
+        Paragraph::new(lines)
+            .block(
+                Block::bordered()
+                    .title(t("info.title"))
+                    .border_style(border_style(self.focused)),
+            )
+            .render(area, buf);
+    }
+}
@@ -2,28 +2,55 @@ use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
+    text::Line,
     widgets::{Block, Borders, Gauge, Widget},
 };
 
-use crate::pokemon::PokemonIV;
+use crate::{
+    i18n::tr,
+    pokemon::{Locale, PokemonIV},
+    widget::border_style,
+};
 
 pub struct IVStatusBar<'a> {
     title: &'a str,
+    title_width: u16,
+    title_style: Style,
     value: u16,
     max: f32,
 }
 
 impl<'a> IVStatusBar<'a> {
     pub fn new(title: &'a str, value: u16, max: f32) -> Self {
-        IVStatusBar { title, value, max }
+        IVStatusBar {
+            title,
+            title_width: 6,
+            title_style: Style::default(),
+            value,
+            max,
+        }
+    }
+
+    /// width of the title column, to align bars with longer titles
+    pub fn title_width(mut self, width: u16) -> Self {
+        self.title_width = width;
+        self
+    }
+
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.title_style = style;
+        self
     }
 }
 
 impl<'a> Widget for IVStatusBar<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let layout = Layout::horizontal([Constraint::Length(6), Constraint::Min(0)]).split(area);
+        let layout = Layout::horizontal([Constraint::Length(self.title_width), Constraint::Min(0)])
+            .split(area);
 
-        Block::default().title(self.title).render(layout[0], buf);
+        Block::default()
+            .title(Line::styled(self.title, self.title_style))
+            .render(layout[0], buf);
 
         Gauge::default()
             .block(Block::default().borders(Borders::NONE))
@@ -41,11 +68,23 @@ impl<'a> Widget for IVStatusBar<'a> {
 #[derive(Copy, Clone, Default)]
 pub struct IVStatus {
     iv: PokemonIV,
+    locale: Locale,
+    focused: bool,
 }
 
 impl IVStatus {
-    pub fn new(iv: PokemonIV) -> IVStatus {
-        IVStatus { iv }
+    pub fn new(iv: PokemonIV, locale: Locale) -> IVStatus {
+        IVStatus {
+            iv,
+            locale,
+            focused: false,
+        }
+    }
+
+    /// titles are highlighted like the border of other panes
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
     }
 
     pub fn get_pokemon_iv_highest(&self) -> f32 {
@@ -75,11 +114,29 @@ impl Widget for IVStatus {
 
         let max = self.get_pokemon_iv_highest();
 
-        IVStatusBar::new("HP", self.iv.hp, max).render(layout[0], buf);
-        IVStatusBar::new("Atk", self.iv.att, max).render(layout[2], buf);
-        IVStatusBar::new("Def", self.iv.def, max).render(layout[4], buf);
-        IVStatusBar::new("S.Atk", self.iv.s_att, max).render(layout[6], buf);
-        IVStatusBar::new("S.Def", self.iv.s_def, max).render(layout[8], buf);
-        IVStatusBar::new("Spd", self.iv.spd, max).render(layout[10], buf);
+        let bars = [
+            ("stat.hp", self.iv.hp),
+            ("stat.atk", self.iv.att),
+            ("stat.def", self.iv.def),
+            ("stat.s_atk", self.iv.s_att),
+            ("stat.s_def", self.iv.s_def),
+            ("stat.spd", self.iv.spd),
+        ]
+        .map(|(key, value)| (tr(self.locale, key), value));
+
+        // at least 6 wide as the english titles, plus a space
+        let title_width = bars
+            .iter()
+            .map(|(title, _)| Line::from(*title).width() as u16 + 1)
+            .max()
+            .unwrap_or(0)
+            .max(6);
+
+        for (i, (title, value)) in bars.into_iter().enumerate() {
+            IVStatusBar::new(title, value, max)
+                .title_width(title_width)
+                .title_style(border_style(self.focused))
+                .render(layout[i * 2], buf);
+        }
     }
 }
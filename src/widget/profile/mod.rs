@@ -1,28 +1,31 @@
 mod ability;
+mod dex;
+mod info;
 mod iv;
 mod overview;
 
-use std::rc::Rc;
-
-use ansi_to_tui::IntoText;
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Layout, Rect},
-    widgets::{Block, Paragraph, StatefulWidget, Widget},
+    widgets::{Block, Borders, Paragraph, StatefulWidget, Widget},
 };
 
 use crate::{
-    pokemon::PokemonEntity,
-    state::{pokemon::AsciiType, tui::TuiState, PokemonListState},
+    sprite::{ImageProtocol, SpriteImage, KITTY_CLEAR},
+    state::{
+        tui::{Pane, TuiState},
+        PokemonListState,
+    },
+    widget::{border_style, missing_art},
 };
 
-use self::ability::AbilityParaGraph;
+use self::{ability::AbilityParaGraph, dex::DexParagraph};
 
-use {iv::IVStatus, overview::Overview};
+use {info::SpeciesInfo, iv::IVStatus, overview::Overview};
 
-// [name with type, ansi, iv, ability, navigation]
+// [name with type, ansi, iv, info and ability, navigation]
 type ProfileLayout = [Rect; 5];
-// [ansi, iv, ability]
+// [ansi, iv, info and ability]
 type ProfileBodyLayout = [Rect; 3];
 
 #[derive(Copy, Clone)]
@@ -30,6 +33,7 @@ struct LayoutParam {
     ansi_height: u16,
     ansi_width: u16,
     show_page_navigation: bool,
+    name_height: u16,
 }
 
 pub struct PokemonProfileWidget(pub TuiState);
@@ -40,14 +44,14 @@ impl PokemonProfileWidget {
 
     fn get_render_areas(&self, area: Rect, param: LayoutParam) -> ProfileLayout {
         let [name, _, body, navi] = Layout::vertical([
-            Constraint::Length(1),
+            Constraint::Length(param.name_height),
             Constraint::Length(1),
             Constraint::Min(0),
             Constraint::Length(if param.show_page_navigation { 1 } else { 0 }),
         ])
         .areas(area);
 
-        let [ansi, iv, ability] = if (!self.0.show_abilities && !self.0.show_iv)
+        let [ansi, iv, ability] = if (!self.show_detail() && !self.0.show_iv)
             || body.width <= param.ansi_width
                 && body.height <= param.ansi_height
                 && body.width.saturating_sub(param.ansi_width) < Self::SPACE_WITHOUT_ANSI_H
@@ -69,7 +73,7 @@ impl PokemonProfileWidget {
 
     fn get_constraints_with_iv_ability(&self, remaining_space: u16) -> (Constraint, Constraint) {
         use Constraint::*;
-        match (self.0.show_abilities, self.0.show_iv) {
+        match (self.show_detail(), self.0.show_iv) {
             (true, false) => (Percentage(100), Length(0)),
             (false, true) => (Length(0), Percentage(100)),
             (false, false) => (Length(0), Length(0)),
@@ -111,7 +115,7 @@ impl PokemonProfileWidget {
             } else {
                 Length(0)
             };
-            let ability = if self.0.show_abilities {
+            let ability = if self.show_detail() {
                 Min(0)
             } else {
                 Length(0)
@@ -121,7 +125,7 @@ impl PokemonProfileWidget {
             [left, top, bottom]
         } else {
             let iv = if self.0.show_iv { Min(0) } else { Length(0) };
-            let ability = if self.0.show_abilities {
+            let ability = if self.show_detail() {
                 Min(0)
             } else {
                 Length(0)
@@ -133,6 +137,35 @@ impl PokemonProfileWidget {
         }
     }
 
+    // [info, dex, ability]
+    /// `show_dex` is off for species without dex entries
+    fn get_detail_areas(&self, detail: Rect, show_dex: bool) -> [Rect; 3] {
+        use Constraint::*;
+        let info = if self.0.show_info {
+            Length(SpeciesInfo::HEIGHT)
+        } else {
+            Length(0)
+        };
+
+        let dex = if show_dex {
+            Length(DexParagraph::HEIGHT)
+        } else {
+            Length(0)
+        };
+
+        // the last visible section takes the remaining space
+        match (show_dex, self.0.show_abilities) {
+            (_, true) => Layout::vertical([info, dex, Min(0)]),
+            (true, false) => Layout::vertical([info, Min(0), Length(0)]),
+            (false, false) => Layout::vertical([Min(0), Length(0), Length(0)]),
+        }
+        .areas(detail)
+    }
+
+    fn show_detail(&self) -> bool {
+        self.0.show_abilities || self.0.show_info || self.0.show_dex
+    }
+
     fn get_only_ansi_areas(&self, body: Rect) -> ProfileBodyLayout {
         Layout::vertical([
             Constraint::Percentage(100),
@@ -141,20 +174,36 @@ impl PokemonProfileWidget {
         ])
         .areas(body)
     }
+}
+
+/// put the escape sequence of `sprite` in the top left cell of `area`,
+/// the other cells it covers are skipped so the text under it does not draw over it
+fn render_sprite(sprite: &SpriteImage, area: Rect, buf: &mut Buffer) {
+    for y in area.y..area.y + sprite.rows {
+        for x in area.x..area.x + sprite.cols {
+            buf[(x, y)].reset();
+            buf[(x, y)].set_skip(true);
+        }
+    }
 
-    fn get_lowercase_name(&self, profile: Rc<PokemonEntity>) -> String {
-        profile
-            .default_name()
-            .replace("Galarian form", "galar")
-            .replace("Alola Form", "alola")
-            .replace("Hisuian form", "hisui")
-            .replace("Paldea form", "paldea")
-            .replace(" - ", "-")
-            .replace(' ', "-")
-            .replace('♀', "-f")
-            .replace('♂', "-m")
-            .to_lowercase()
+    // the cursor is saved and restored around it, as terminals move it past the image
+    buf[(area.x, area.y)]
+        .set_skip(false)
+        .set_symbol(&format!("\x1b7{}\x1b8", sprite.escape));
+}
+
+/// kitty images stay over the text until deleted, this is written once when the sprite goes away
+///
+/// the last cell is used since the width of a long symbol hides the cell after it from the diff
+fn clear_kitty_sprite(buf: &mut Buffer) {
+    let area = buf.area;
+    if area.is_empty() {
+        return;
     }
+
+    let cell = &mut buf[(area.right() - 1, area.bottom() - 1)];
+    let symbol = format!("{}{}", KITTY_CLEAR, cell.symbol());
+    cell.set_symbol(&symbol);
 }
 
 impl StatefulWidget for PokemonProfileWidget {
@@ -166,64 +215,95 @@ impl StatefulWidget for PokemonProfileWidget {
         };
 
         let region_form_page_num = state.region_form_len();
-        let lowercase_name = self.get_lowercase_name(profile.clone());
-
-        let ascii_form = state
-            .ascii_form_map
-            .get(&lowercase_name)
-            .and_then(|forms| forms.get(state.ascii_form_index % forms.len()))
-            .map_or("", |f| f);
-
-        let ascii_type = if ascii_form == "shiny" {
-            AsciiType::Shiny
-        } else {
-            AsciiType::Normal
+        // images are drawn over the text by the terminal, a popup would be hidden under them
+        let sprite = if self.0.popup { None } else { state.sprite() };
+        let ansi_text = match state.art() {
+            Some(ansi) => (*ansi).clone(),
+            None => missing_art(
+                state.current_art_key(false).map(|key| key.entry()),
+                state.locale,
+            ),
         };
-
-        let ascii_form = if ascii_form == "regular" || ascii_form == "shiny" {
-            String::from("")
-        } else if !ascii_form.is_empty() {
-            format!("-{}", ascii_form)
-        } else {
-            ascii_form.to_string()
+        let (ansi_width, ansi_height) = match &sprite {
+            Some(sprite) => (sprite.cols + 1, sprite.rows + 1),
+            None => (ansi_text.width() as u16 + 1, ansi_text.height() as u16 + 1),
         };
 
-        let (ansi_width, ansi_height, ansi_text) = std::fs::read(
-            state
-                .get_assets_path(ascii_type)
-                .join(lowercase_name + &ascii_form),
-        )
-        .map(|buffer| buffer.into_text().ok())
-        .ok()
-        .flatten()
-        .map_or((0u16, 0u16, None), |ansi| {
-            (
-                ansi.width() as u16 + 1,
-                ansi.height() as u16 + 1,
-                Some(ansi),
-            )
-        });
+        let alt_names = state.alt_names_of(&profile);
 
-        let [name, ansi, iv, ability, navigation] = self.get_render_areas(
+        let [name, ansi, iv, detail, navigation] = self.get_render_areas(
             area,
             LayoutParam {
                 ansi_height,
                 ansi_width,
                 show_page_navigation: region_form_page_num > 1,
+                name_height: Overview::height(alt_names.len()),
             },
         );
 
-        AbilityParaGraph(state.bundle.get_ability_text(&profile)).render(
-            ability,
-            buf,
-            &mut state.desc_scrollbar_state,
-        );
+        let dex_text = state.bundle.get_dex_text(&profile, state.locale);
+        let show_dex = self.0.show_dex && !dex_text.is_empty();
+        let [info, dex, ability] = self.get_detail_areas(detail, show_dex);
 
-        Overview::new(profile.name.get(), profile.r#type).render(name, buf);
-        IVStatus::new(profile.iv).render(iv, buf);
+        if self.0.show_info && !info.is_empty() {
+            SpeciesInfo::new(&profile.species, profile.generation(), state.locale)
+                .focused(self.0.is_focused(Pane::Info))
+                .render(info, buf);
+        }
 
-        if let Some(ansi_text) = ansi_text {
-            Paragraph::new(ansi_text).render(ansi, buf);
+        // the pane is left out for species without entries
+        state.mouse.dex = dex;
+        if show_dex && !dex.is_empty() {
+            DexParagraph::new(dex_text, state.dex_version_index, state.locale)
+                .focused(self.0.is_focused(Pane::Dex))
+                .render(dex, buf, &mut state.dex_scrollbar_state);
+        }
+
+        if self.0.show_abilities && !ability.is_empty() {
+            state.mouse.ability = ability;
+            AbilityParaGraph::new(
+                state.bundle.get_ability_text(&profile, state.locale),
+                state.locale,
+            )
+            .focused(self.0.is_focused(Pane::Abilities))
+            .render(ability, buf, &mut state.desc_scrollbar_state);
+        }
+
+        Overview::new(profile.name.get(state.locale), profile.r#type, state.locale)
+            .alt_names(alt_names)
+            .render(name, buf);
+        IVStatus::new(profile.iv, state.locale)
+            .focused(self.0.is_focused(Pane::Stats))
+            .render(iv, buf);
+
+        // the art is drawn instead of a sprite that does not fit
+        match sprite.filter(|sprite| sprite.cols <= ansi.width && sprite.rows <= ansi.height) {
+            Some(sprite) => render_sprite(&sprite, ansi, buf),
+            None => {
+                Paragraph::new(ansi_text).render(ansi, buf);
+                if state
+                    .sprites
+                    .as_ref()
+                    .is_some_and(|sprites| sprites.protocol == ImageProtocol::Kitty)
+                {
+                    clear_kitty_sprite(buf);
+                }
+            }
+        }
+
+        // the art has no border, the spare row under it is drawn as one when focused
+        if self.0.is_focused(Pane::Art) {
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(border_style(true))
+                .render(
+                    Rect {
+                        width: ansi.width.min(ansi_width.max(1)),
+                        height: ansi.height.min(ansi_height.max(1)),
+                        ..ansi
+                    },
+                    buf,
+                );
         }
 
         if region_form_page_num > 1 {
@@ -233,6 +313,13 @@ impl StatefulWidget for PokemonProfileWidget {
                 region_form_page_num,
             );
 
+            let width = (title.chars().count() as u16).min(navigation.width);
+            state.mouse.navigation = Rect {
+                x: navigation.x + (navigation.width - width) / 2,
+                width,
+                ..navigation
+            };
+
             Block::default()
                 .title(title)
                 .title_alignment(Alignment::Center)
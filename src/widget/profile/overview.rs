@@ -1,19 +1,37 @@
 use ratatui::{
+    layout::Rect,
     style::{Color, Style},
-    text::Span,
-    widgets::{Block, Borders, Widget},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
 };
 
-use crate::pokemon::{PokemonType, PokemonTypeKind};
+use crate::pokemon::{Locale, PokemonType, PokemonTypeKind};
 
 pub struct Overview {
     pub name: String,
     pub pm_type: PokemonType,
+    pub locale: Locale,
+    /// shown under the name, one per line
+    pub alt_names: Vec<String>,
 }
 
 impl Overview {
-    pub fn new(name: String, pm_type: PokemonType) -> Self {
-        Self { name, pm_type }
+    pub fn new(name: String, pm_type: PokemonType, locale: Locale) -> Self {
+        Self {
+            name,
+            pm_type,
+            locale,
+            alt_names: vec![],
+        }
+    }
+
+    pub fn alt_names(mut self, alt_names: Vec<String>) -> Self {
+        self.alt_names = alt_names;
+        self
+    }
+
+    pub fn height(alt_names: usize) -> u16 {
+        1 + alt_names as u16
     }
 }
 
@@ -22,7 +40,7 @@ impl Widget for Overview {
         let mut type_span = vec![
             Span::from(self.name + " "),
             Span::styled(
-                self.pm_type.0.to_string(),
+                self.pm_type.0.name(self.locale),
                 Style::default().bg(self.pm_type.0.color()).fg(Color::White),
             ),
             Span::from(" "),
@@ -31,7 +49,7 @@ impl Widget for Overview {
         if let Some(kind) = self.pm_type.1 {
             if kind != PokemonTypeKind::Other {
                 type_span.push(Span::styled(
-                    kind.to_string(),
+                    kind.name(self.locale),
                     Style::default().bg(kind.color()).fg(Color::White),
                 ));
             }
@@ -41,5 +59,16 @@ impl Widget for Overview {
             .title(type_span)
             .borders(Borders::NONE)
             .render(area, buf);
+
+        let alt_names = Rect {
+            y: area.y + 1,
+            height: area.height.saturating_sub(1),
+            ..area
+        };
+        let lines = self
+            .alt_names
+            .into_iter()
+            .map(|name| Line::styled(name, Style::default().fg(Color::DarkGray)));
+        Paragraph::new(lines.collect::<Vec<_>>()).render(alt_names, buf);
     }
 }
@@ -0,0 +1,147 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Text},
+    widgets::{Block, Clear, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::{
+    i18n::{tr, trf},
+    state::{
+        quiz::{QuizOutcome, QUIZ_TRIES},
+        AppState,
+    },
+    widget::missing_art,
+};
+
+pub struct Quiz;
+
+impl Quiz {
+    const SILHOUETTE: Color = Color::DarkGray;
+
+    /// every colored cell in one color
+    fn silhouette(mut text: Text) -> Text {
+        let flatten = |color: Option<Color>| {
+            color.map(|c| match c {
+                Color::Reset => Color::Reset,
+                _ => Self::SILHOUETTE,
+            })
+        };
+
+        for span in text.lines.iter_mut().flat_map(|line| line.spans.iter_mut()) {
+            span.style.fg = flatten(span.style.fg);
+            span.style.bg = flatten(span.style.bg);
+        }
+
+        text
+    }
+}
+
+impl StatefulWidget for Quiz {
+    type State = AppState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let Some(pm) = state.quiz.current.clone() else {
+            return;
+        };
+
+        Clear.render(area, buf);
+
+        let locale = state.locale();
+        let quiz = &state.quiz;
+        let block = Block::bordered()
+            .title(tr(locale, "quiz.title"))
+            .title_alignment(Alignment::Center);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [score, art, feedback, input, hint] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(2),
+            Constraint::Length(3),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+
+        Paragraph::new(trf(
+            locale,
+            "quiz.score",
+            &[
+                &quiz.score,
+                &quiz.streak,
+                &quiz.record.high_score,
+                &quiz.record.best_streak,
+            ],
+        ))
+        .alignment(Alignment::Center)
+        .render(score, buf);
+
+        let list = &mut state.pokemon_list;
+        let key = list.art_key(&pm, "regular", false);
+        let text = match list.art_cache.get(&key).map(|art| (*art).clone()) {
+            Some(text) if quiz.is_answered() => text,
+            Some(text) => Self::silhouette(text),
+            // the file name would give the answer away
+            None => missing_art(quiz.is_answered().then(|| key.entry()), locale),
+        };
+        Paragraph::new(text)
+            .alignment(Alignment::Center)
+            .render(art, buf);
+
+        let answer = || {
+            let alt_names = state.pokemon_list.alt_names_of(&pm);
+            let names = std::iter::once(pm.name_with_no(locale))
+                .chain(alt_names)
+                .collect::<Vec<_>>();
+            names.join(" / ")
+        };
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let lines = match quiz.outcome {
+            Some(QuizOutcome::Correct(points)) => vec![
+                Line::styled(
+                    trf(locale, "quiz.correct", &[&answer(), &points]),
+                    bold.fg(Color::Green),
+                ),
+                Line::from(tr(locale, "quiz.next")),
+            ],
+            Some(QuizOutcome::Wrong(left)) => vec![Line::styled(
+                trf(locale, "quiz.wrong", &[&left]),
+                Style::default().fg(Color::Red),
+            )],
+            Some(QuizOutcome::Revealed) => vec![
+                Line::styled(trf(locale, "quiz.reveal", &[&answer()]), bold),
+                Line::from(tr(locale, "quiz.next")),
+            ],
+            None => vec![Line::from(trf(locale, "quiz.tries", &[&QUIZ_TRIES]))],
+        };
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .render(feedback, buf);
+
+        let [input] = Layout::horizontal([Constraint::Max(40)])
+            .flex(Flex::Center)
+            .areas(input);
+        let width = input.width.saturating_sub(2) as usize;
+        let scroll = quiz.input.visual_scroll(width);
+        Paragraph::new(quiz.input.value())
+            .style(Style::default().fg(Color::Yellow))
+            .scroll((0, scroll as u16))
+            .block(Block::bordered())
+            .render(input, buf);
+
+        state.tui.cursor = (!quiz.is_answered()).then(|| {
+            (
+                input.x + (quiz.input.visual_cursor().max(scroll) - scroll) as u16 + 1,
+                input.y + 1,
+            )
+        });
+
+        Paragraph::new(tr(locale, "quiz.hint"))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray))
+            .render(hint, buf);
+    }
+}
@@ -0,0 +1,166 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::{
+    i18n::{tr, trf},
+    pokemon::{Effectiveness, Locale, PokemonType, PokemonTypeKind},
+    state::AppState,
+};
+
+pub struct Trainer;
+
+impl Trainer {
+    fn type_span(t: PokemonTypeKind, locale: Locale) -> Span<'static> {
+        Span::styled(
+            t.name(locale),
+            Style::default().fg(t.color()).add_modifier(Modifier::BOLD),
+        )
+    }
+
+    fn types_spans(types: PokemonType, locale: Locale) -> Vec<Span<'static>> {
+        let mut spans = vec![Self::type_span(types.0, locale)];
+        if let Some(second) = types.1 {
+            spans.push(Span::raw(" / "));
+            spans.push(Self::type_span(second, locale));
+        }
+        spans
+    }
+}
+
+impl StatefulWidget for Trainer {
+    type State = AppState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let Some(question) = &state.trainer.question else {
+            return;
+        };
+
+        Clear.render(area, buf);
+
+        let locale = state.locale();
+        let trainer = &state.trainer;
+        let matchup = question.matchup;
+        let block = Block::bordered()
+            .title(tr(locale, "trainer.title"))
+            .title_alignment(Alignment::Center);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [stats, _, question_area, _, choices, feedback, hint] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+
+        // overall accuracy and the types missed most
+        let record = &trainer.record;
+        let weakest = record
+            .weakest(3)
+            .into_iter()
+            .map(|(t, accuracy)| format!("{} {}%", t.name(locale), accuracy.percent()))
+            .collect::<Vec<_>>();
+        let mut lines = vec![Line::from(trf(
+            locale,
+            "trainer.stats",
+            &[
+                &record.answered,
+                &record.accuracy().percent(),
+                &record.due_reviews(),
+            ],
+        ))];
+        if !weakest.is_empty() {
+            lines.push(Line::from(trf(
+                locale,
+                "trainer.weakest",
+                &[&weakest.join(", ")],
+            )));
+        }
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray))
+            .render(stats, buf);
+
+        // `Ice vs Dragonite (Dragon / Flying)?`
+        let mut spans = vec![Self::type_span(matchup.attack, locale), Span::raw(" vs ")];
+        match &question.species {
+            Some(pm) => {
+                spans.push(Span::raw(format!("{} (", pm.name.get(locale))));
+                spans.extend(Self::types_spans(matchup.defense, locale));
+                spans.push(Span::raw(")?"));
+            }
+            None => {
+                spans.extend(Self::types_spans(matchup.defense, locale));
+                spans.push(Span::raw("?"));
+            }
+        }
+        Paragraph::new(Line::from(spans))
+            .alignment(Alignment::Center)
+            .render(question_area, buf);
+
+        let effectiveness = matchup.effectiveness();
+        let choices_line = Effectiveness::ALL
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, e)| {
+                let style = match trainer.answer {
+                    Some(_) if e == effectiveness => Style::default().fg(Color::Green),
+                    Some(answer) if e == answer => Style::default().fg(Color::Red),
+                    _ => Style::default(),
+                };
+                [
+                    Span::raw(if i > 0 { "  " } else { "" }),
+                    Span::styled(format!("{}: ", i + 1), Style::default().fg(Color::DarkGray)),
+                    Span::styled(e.to_string(), style),
+                ]
+            })
+            .collect::<Vec<_>>();
+        Paragraph::new(Line::from(choices_line))
+            .alignment(Alignment::Center)
+            .render(choices, buf);
+
+        if trainer.is_answered() {
+            let bold = Style::default().add_modifier(Modifier::BOLD);
+            let result = if trainer.is_correct() {
+                Line::styled(
+                    trf(locale, "trainer.correct", &[&effectiveness]),
+                    bold.fg(Color::Green),
+                )
+            } else {
+                Line::styled(
+                    trf(locale, "trainer.wrong", &[&effectiveness]),
+                    bold.fg(Color::Red),
+                )
+            };
+
+            // one row of the type chart for each defending type
+            let mut chart = vec![Self::type_span(matchup.attack, locale), Span::raw(" → ")];
+            let defense = [Some(matchup.defense.0), matchup.defense.1];
+            for (i, t) in defense.into_iter().flatten().enumerate() {
+                if i > 0 {
+                    chart.push(Span::raw(", "));
+                }
+                chart.push(Self::type_span(t, locale));
+                chart.push(Span::raw(format!(" {}", matchup.attack.against(t))));
+            }
+
+            Paragraph::new(vec![Line::from(""), result, Line::from(chart)])
+                .alignment(Alignment::Center)
+                .render(feedback, buf);
+        }
+
+        Paragraph::new(tr(locale, "trainer.hint"))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray))
+            .render(hint, buf);
+    }
+}
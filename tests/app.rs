@@ -0,0 +1,956 @@
+mod common;
+
+use poketex::{
+    app::run_app,
+    event::ScriptedEvents,
+    pokemon::{AltName, Effectiveness, Locale, PokemonType, PokemonTypeKind, SortKey},
+    sprite::{ImageProtocol, SpriteRenderer},
+    state::{
+        art::{ArtCache, ArtKey},
+        command::{Command, CommandError, CommandMessage, SetValue},
+        pokemon::{MouseState, ScrollTarget},
+        quiz::{QuizOutcome, QuizRecord, QuizState},
+        trainer::{Matchup, TrainerState, TypeQuestion},
+        tui::Pane,
+        AppState, InputMode,
+    },
+};
+use std::time::Duration;
+
+use ratatui::{
+    backend::TestBackend,
+    crossterm::event::{Event, KeyCode, KeyModifiers, MouseButton, MouseEventKind},
+    Terminal,
+};
+
+/// size of the terminal the app runs in, small enough for long abilities to scroll
+const SIZE: (u16, u16) = (120, 30);
+
+fn run(mut events: ScriptedEvents) -> AppState {
+    let mut app = common::app();
+    let mut terminal = Terminal::new(TestBackend::new(SIZE.0, SIZE.1)).unwrap();
+    run_app(&mut terminal, &mut app, &mut events).unwrap();
+    app
+}
+
+/// keep running `app` with more events
+fn run_on(app: &mut AppState, mut events: ScriptedEvents) {
+    let mut terminal = Terminal::new(TestBackend::new(SIZE.0, SIZE.1)).unwrap();
+    run_app(&mut terminal, app, &mut events).unwrap();
+}
+
+fn selected(app: &AppState) -> Option<usize> {
+    app.pokemon_list.list_state.selected()
+}
+
+fn profile_no(app: &AppState) -> Option<u16> {
+    app.pokemon_list.profile().map(|pm| pm.no)
+}
+
+fn search(query: &str) -> ScriptedEvents {
+    ScriptedEvents::keys("/")
+        .then_keys(query)
+        .then_key(KeyCode::Enter, KeyModifiers::NONE)
+}
+
+fn command(line: &str) -> ScriptedEvents {
+    ScriptedEvents::keys(":")
+        .then_keys(line)
+        .then_key(KeyCode::Enter, KeyModifiers::NONE)
+}
+
+#[test]
+fn next_and_previous() {
+    let app = run(ScriptedEvents::keys("jjjk"));
+    assert_eq!(selected(&app), Some(2));
+    assert_eq!(profile_no(&app), Some(3));
+}
+
+#[test]
+fn previous_wraps_to_last() {
+    let app = run(ScriptedEvents::keys("k"));
+    assert_eq!(selected(&app), Some(app.pokemon_list.len() - 1));
+    assert_eq!(profile_no(&app), Some(1025));
+}
+
+#[test]
+fn next_wraps_to_first() {
+    let app = run(ScriptedEvents::keys("Gj"));
+    assert_eq!(selected(&app), Some(0));
+}
+
+#[test]
+fn vim_commands() {
+    let app = run(ScriptedEvents::keys("25G"));
+    assert_eq!(profile_no(&app), Some(25));
+    assert!(app.vim_cmd.is_empty());
+
+    let app = run(ScriptedEvents::keys("25Ggg"));
+    assert_eq!(selected(&app), Some(0));
+
+    let app = run(ScriptedEvents::keys("12g"));
+    assert_eq!(app.vim_cmd, "12g");
+
+    let app = run(ScriptedEvents::keys("12").then_key(KeyCode::Esc, KeyModifiers::NONE));
+    assert!(app.vim_cmd.is_empty());
+}
+
+#[test]
+fn page_scroll() {
+    let app = run(ScriptedEvents::default()
+        .then_key(KeyCode::PageDown, KeyModifiers::NONE)
+        .then_key(KeyCode::Char('f'), KeyModifiers::CONTROL));
+    assert_eq!(selected(&app), Some(8));
+
+    let app = run(ScriptedEvents::keys("5G").then_key(KeyCode::PageUp, KeyModifiers::NONE));
+    assert_eq!(selected(&app), Some(0));
+}
+
+#[test]
+fn region_form_pages() {
+    // Growlithe has one Hisuian form
+    let app = run(ScriptedEvents::keys("58Gl"));
+    assert_eq!(app.pokemon_list.profile_page, 1);
+
+    let app = run(ScriptedEvents::keys("58Glll"));
+    assert_eq!(app.pokemon_list.profile_page, 1);
+
+    let app = run(ScriptedEvents::keys("58Glhh"));
+    assert_eq!(app.pokemon_list.profile_page, 0);
+
+    // Bulbasaur has none
+    let app = run(ScriptedEvents::keys("l"));
+    assert_eq!(app.pokemon_list.profile_page, 0);
+
+    let app = run(ScriptedEvents::keys("58Glj"));
+    assert_eq!(app.pokemon_list.profile_page, 0);
+}
+
+#[test]
+fn ascii_form_index() {
+    let app = run(ScriptedEvents::keys("fff"));
+    assert_eq!(app.pokemon_list.ascii_form_index, 3);
+
+    let app = run(ScriptedEvents::keys("fffj"));
+    assert_eq!(app.pokemon_list.ascii_form_index, 0);
+
+    let app = run(ScriptedEvents::keys("3Gfl"));
+    assert_eq!(app.pokemon_list.ascii_form_index, 0);
+}
+
+#[test]
+fn shiny_applies_to_every_form() {
+    let art = |app: &AppState| {
+        let path = app.pokemon_list.current_ascii_path().unwrap();
+        let dir = path.parent().unwrap().file_name().unwrap().to_owned();
+        (
+            dir.into_string().unwrap(),
+            path.file_name().unwrap().to_owned(),
+        )
+    };
+
+    // charizard forms are regular, gmax, mega-x and mega-y, without shiny among them
+    let app = run(ScriptedEvents::keys("6Gf"));
+    assert_eq!(app.pokemon_list.ascii_forms().unwrap().len(), 4);
+    assert_eq!(
+        art(&app),
+        (String::from("regular"), "charizard-gmax".into())
+    );
+
+    let app = run(ScriptedEvents::keys("6GfS"));
+    assert_eq!(art(&app), (String::from("shiny"), "charizard-gmax".into()));
+
+    // shiny stays on while switching forms and pokemon, region forms too
+    let app = run(ScriptedEvents::keys("6GSff"));
+    assert_eq!(
+        art(&app),
+        (String::from("shiny"), "charizard-mega-x".into())
+    );
+    let app = run(ScriptedEvents::keys("S58Gl"));
+    assert_eq!(art(&app), (String::from("shiny"), "growlithe-hisui".into()));
+
+    let app = run(ScriptedEvents::keys("6GS").chain(command("set noshiny")));
+    assert_eq!(art(&app).0, "regular");
+    let app = run(command("set shiny!"));
+    assert!(app.pokemon_list.shiny);
+    assert!(app.command_line.message.is_none());
+}
+
+#[test]
+fn filter_narrows_list() {
+    let app = run(search("saur"));
+    assert!(matches!(app.tui.input_mode, InputMode::Normal));
+    assert_eq!(app.pokemon_list.filter_query, "saur");
+    assert_eq!(app.pokemon_list.list_items().len(), 3);
+    assert_eq!(profile_no(&app), Some(1));
+
+    let app = run(search("saur").then_keys("j"));
+    assert_eq!(profile_no(&app), Some(2));
+}
+
+#[test]
+fn filter_escape_resets() {
+    let app = run(ScriptedEvents::keys("/pika").then_key(KeyCode::Esc, KeyModifiers::NONE));
+    assert!(matches!(app.tui.input_mode, InputMode::Normal));
+    assert!(app.pokemon_list.filter_query.is_empty());
+    assert_eq!(profile_no(&app), Some(1));
+}
+
+#[test]
+fn filter_keys_are_not_commands() {
+    let app = run(ScriptedEvents::keys("/qG"));
+    assert!(matches!(app.tui.input_mode, InputMode::Editing));
+    assert_eq!(app.pokemon_list.filter_query, "qG");
+    assert!(app.vim_cmd.is_empty());
+}
+
+#[test]
+fn filter_previous_wraps_to_last() {
+    let app = run(search("saur").then_keys("k"));
+    assert_eq!(selected(&app), Some(2));
+    assert_eq!(profile_no(&app), Some(3));
+}
+
+#[test]
+fn filter_next_wraps_to_first() {
+    let app = run(search("saur").then_keys("jjj"));
+    assert_eq!(profile_no(&app), Some(1));
+}
+
+#[test]
+fn filter_bounds_end_and_page_scroll() {
+    let app = run(search("saur").then_keys("G"));
+    assert_eq!(profile_no(&app), Some(3));
+
+    let app = run(search("saur").then_key(KeyCode::PageDown, KeyModifiers::NONE));
+    assert_eq!(selected(&app), Some(2));
+}
+
+#[test]
+fn empty_filter_selects_nothing() {
+    let app = run(search("zzzz").then_keys("jkGgg"));
+    assert_eq!(selected(&app), None);
+    assert_eq!(profile_no(&app), None);
+}
+
+#[test]
+fn quit_stops_handling_events() {
+    let app = run(ScriptedEvents::keys("jqj"));
+    assert_eq!(selected(&app), Some(1));
+}
+
+#[test]
+fn command_parse() {
+    assert_eq!(Command::parse("so spd"), Ok(Command::Sort(SortKey::Spd)));
+    assert_eq!(Command::parse("42"), Ok(Command::Goto(String::from("42"))));
+    assert_eq!(
+        Command::parse("set noiv"),
+        Ok(Command::Set {
+            option: String::from("iv"),
+            value: SetValue::Off
+        })
+    );
+    assert_eq!(
+        Command::parse("s iv"),
+        Err(CommandError::Unknown(String::from("s")))
+    );
+    assert_eq!(
+        Command::parse("lang xx"),
+        Err(CommandError::InvalidArgument {
+            command: "lang",
+            value: String::from("xx")
+        })
+    );
+}
+
+#[test]
+fn command_goto() {
+    let app = run(command("goto pikachu"));
+    assert_eq!(profile_no(&app), Some(25));
+    assert!(matches!(app.tui.input_mode, InputMode::Normal));
+
+    let app = run(command("150"));
+    assert_eq!(profile_no(&app), Some(150));
+}
+
+#[test]
+fn command_goto_clears_hiding_filter() {
+    let app = run(search("saur").chain(command("goto pikachu")));
+    assert!(app.pokemon_list.filter_query.is_empty());
+    assert_eq!(profile_no(&app), Some(25));
+}
+
+#[test]
+fn command_type_filters_list() {
+    let app = run(command("type fire flying"));
+    assert!(!app.pokemon_list.is_empty());
+    assert_eq!(profile_no(&app), Some(6));
+
+    let app = run(command("type fie"));
+    assert!(matches!(
+        app.command_line.message,
+        Some(CommandMessage::Error(_))
+    ));
+}
+
+#[test]
+fn command_sort_keeps_selection() {
+    let app = run(ScriptedEvents::keys("jj").chain(command("sort spd")));
+    let list = app.pokemon_list.list_items();
+    assert!(list.windows(2).all(|w| w[0].iv.spd >= w[1].iv.spd));
+    assert_eq!(profile_no(&app), Some(3));
+
+    let app = run(command("sort spd").chain(command("sort no")));
+    assert_eq!(app.pokemon_list.list_items()[0].no, 1);
+}
+
+#[test]
+fn command_set_options() {
+    let app = run(command("set noiv").chain(command("set list!")));
+    assert!(!app.tui.show_iv);
+    assert!(!app.tui.show_list);
+
+    // sprites need a sprite directory and an image protocol
+    let mut app = common::app();
+    let set_sprites = || Command::Set {
+        option: "sprites".to_string(),
+        value: SetValue::Toggle,
+    };
+    assert!(app.execute(set_sprites()).unwrap().is_some());
+    app.pokemon_list.sprites = Some(SpriteRenderer::new(ImageProtocol::Kitty, "sprites".into()));
+    assert_eq!(app.execute(set_sprites()), Ok(None));
+    assert!(!app.pokemon_list.sprites.as_ref().unwrap().enabled);
+
+    let set_unknown = Command::Set {
+        option: "shine".to_string(),
+        value: SetValue::On,
+    };
+    assert_eq!(
+        app.execute(set_unknown),
+        Err(CommandError::UnknownOption(String::from("shine")))
+    );
+    assert!(!app.pokemon_list.shiny);
+    assert_eq!(
+        Command::parse("set shine"),
+        Err(CommandError::UnknownOption(String::from("shine")))
+    );
+}
+
+#[test]
+fn command_compare() {
+    let app = run(command("compare charmander"));
+    assert!(app.compare.is_ready());
+
+    let app = run(command("compare").then_keys("j").chain(command("compare")));
+    assert_eq!(app.compare.left.as_ref().map(|pm| pm.no), Some(1));
+    assert_eq!(app.compare.right.as_ref().map(|pm| pm.no), Some(2));
+}
+
+#[test]
+fn command_messages_follow_locale() {
+    let app = run(command("lang ja").chain(command("frobnicate")));
+    assert!(matches!(
+        app.command_line.message,
+        Some(CommandMessage::Error(ref message)) if message == "不明なコマンド: frobnicate"
+    ));
+
+    let app = run(command("lang ja").chain(command("compare")));
+    assert!(matches!(
+        app.command_line.message,
+        Some(CommandMessage::Info(ref message))
+            if message == "フシギダネ を選びました、別のポケモンで :compare を実行"
+    ));
+}
+
+#[test]
+fn command_completion() {
+    let app = run(ScriptedEvents::keys(":go")
+        .then_key(KeyCode::Tab, KeyModifiers::NONE)
+        .then_keys(" pikac")
+        .then_key(KeyCode::Tab, KeyModifiers::NONE));
+    assert_eq!(app.command_line.input.value(), "goto Pikachu");
+
+    let app = run(ScriptedEvents::keys(":s")
+        .then_key(KeyCode::Tab, KeyModifiers::NONE)
+        .then_key(KeyCode::Tab, KeyModifiers::NONE));
+    assert_eq!(app.command_line.input.value(), "sort");
+}
+
+#[test]
+fn command_history() {
+    let app = run(command("goto pikachu")
+        .chain(command("sort hp"))
+        .then_keys(":")
+        .then_key(KeyCode::Up, KeyModifiers::NONE)
+        .then_key(KeyCode::Up, KeyModifiers::NONE));
+    assert_eq!(app.command_line.history.len(), 2);
+    assert_eq!(app.command_line.input.value(), "goto pikachu");
+}
+
+#[test]
+fn command_quit() {
+    let app = run(command("q").then_keys("j"));
+    assert_eq!(selected(&app), Some(0));
+}
+
+#[test]
+fn command_lang_switches_names() {
+    let app = run(command("lang ja"));
+    assert_eq!(app.locale(), Locale::Ja);
+    let pm = app.pokemon_list.profile().unwrap();
+    assert_eq!(
+        pm.name_with_no(app.locale()),
+        format!("#1 {}", pm.name.get(Locale::Ja))
+    );
+
+    let app = run(command("lang xx"));
+    assert_eq!(app.locale(), Locale::En);
+}
+
+#[test]
+fn command_lang_keeps_filter() {
+    let app = run(search("saur").then_keys("j").chain(command("lang ja")));
+    assert_eq!(app.pokemon_list.filter_query, "saur");
+    assert_eq!(app.pokemon_list.len(), 3);
+    assert_eq!(profile_no(&app), Some(2));
+}
+
+#[test]
+fn command_also_shows_alt_names() {
+    let app = run(command("also ja romaji").chain(command("set column")));
+    assert_eq!(
+        app.pokemon_list.alt_names,
+        vec![AltName::Locale(Locale::Ja), AltName::Romaji]
+    );
+    assert!(app.tui.show_list_column);
+
+    let pm = app.pokemon_list.profile().unwrap();
+    assert_eq!(
+        app.pokemon_list.alt_names_of(&pm),
+        vec![String::from("フシギダネ"), String::from("Fushigidane")]
+    );
+
+    // the name of the locale itself is not repeated
+    let app = run(command("also ja en").chain(command("lang ja")));
+    let pm = app.pokemon_list.profile().unwrap();
+    assert_eq!(app.pokemon_list.alt_names_of(&pm), vec![pm.name.en()]);
+
+    let app = run(command("also xx"));
+    assert!(app.pokemon_list.alt_names.is_empty());
+}
+
+#[test]
+fn locale_key_cycles() {
+    let app = run(ScriptedEvents::keys("LL"));
+    assert_eq!(app.locale(), Locale::Zh);
+    assert!(matches!(
+        app.command_line.message,
+        Some(CommandMessage::Info(_))
+    ));
+}
+
+/// areas of the frame drawn after `events`
+fn mouse_areas(events: ScriptedEvents) -> MouseState {
+    run(events).pokemon_list.mouse
+}
+
+#[test]
+fn mouse_click_selects_list_item() {
+    let list = mouse_areas(ScriptedEvents::default()).list;
+    let app = run(ScriptedEvents::default().then_mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        list.x + 1,
+        list.y + 3,
+    ));
+    assert_eq!(selected(&app), Some(3));
+
+    // rows below the last item do nothing
+    let app = run(search("bulbasaur").then_mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        list.x + 1,
+        list.y + 5,
+    ));
+    assert_eq!(selected(&app), Some(0));
+}
+
+#[test]
+fn mouse_wheel_scrolls() {
+    let list = mouse_areas(ScriptedEvents::default()).list;
+    let app = run(ScriptedEvents::default()
+        .then_mouse(MouseEventKind::ScrollDown, list.x, list.y)
+        .then_mouse(MouseEventKind::ScrollDown, list.x, list.y)
+        .then_mouse(MouseEventKind::ScrollUp, list.x, list.y));
+    assert_eq!(selected(&app), Some(1));
+
+    // Growlithe has enough ability text to scroll
+    let ability = mouse_areas(ScriptedEvents::keys("58G")).ability;
+    let app = run(ScriptedEvents::keys("58G")
+        .then_mouse(MouseEventKind::ScrollDown, ability.x + 1, ability.y + 1)
+        .then_mouse(MouseEventKind::ScrollDown, ability.x + 1, ability.y + 1));
+    assert_eq!(app.pokemon_list.desc_scrollbar_state.position, 2);
+    assert_eq!(selected(&app), Some(57));
+}
+
+#[test]
+fn mouse_click_region_form_navigation() {
+    let navigation = mouse_areas(ScriptedEvents::keys("58G")).navigation;
+    let right = navigation.right() - 1;
+
+    let app = run(ScriptedEvents::keys("58G")
+        .then_mouse(MouseEventKind::Down(MouseButton::Left), right, navigation.y)
+        .then_mouse(MouseEventKind::Up(MouseButton::Left), right, navigation.y));
+    assert_eq!(app.pokemon_list.profile_page, 1);
+
+    let app = run(ScriptedEvents::keys("58Gl").then_mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        navigation.x,
+        navigation.y,
+    ));
+    assert_eq!(app.pokemon_list.profile_page, 0);
+}
+
+#[test]
+fn mouse_drag_scrollbars() {
+    let scrollbar = mouse_areas(ScriptedEvents::default()).list_scrollbar;
+    let bottom = scrollbar.bottom() - 1;
+
+    let app = run(ScriptedEvents::default().then_mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        scrollbar.x,
+        bottom,
+    ));
+    assert_eq!(selected(&app), Some(app.pokemon_list.len() - 1));
+    assert_eq!(app.pokemon_list.mouse.dragging, Some(ScrollTarget::List));
+
+    // dragging keeps going outside the scrollbar column
+    let app = run(ScriptedEvents::default()
+        .then_mouse(MouseEventKind::Down(MouseButton::Left), scrollbar.x, bottom)
+        .then_mouse(MouseEventKind::Drag(MouseButton::Left), 0, scrollbar.y)
+        .then_mouse(MouseEventKind::Up(MouseButton::Left), 0, scrollbar.y));
+    assert_eq!(selected(&app), Some(0));
+    assert_eq!(app.pokemon_list.mouse.dragging, None);
+
+    let ability = mouse_areas(ScriptedEvents::keys("58G")).ability;
+    let app = run(ScriptedEvents::keys("58G").then_mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        ability.right() - 1,
+        ability.bottom() - 1,
+    ));
+    let desc = &app.pokemon_list.desc_scrollbar_state;
+    assert_eq!(desc.position, desc.height - 2);
+}
+
+#[test]
+fn message_expires() {
+    let app = run(command("sort xx").then_wait(Duration::from_secs(1)));
+    assert!(matches!(
+        app.command_line.message,
+        Some(CommandMessage::Error(_))
+    ));
+
+    let app = run(command("sort xx").then_wait(Duration::from_secs(4)));
+    assert!(app.command_line.message.is_none());
+    assert!(app.timers.timeout().is_none());
+}
+
+#[test]
+fn paste_into_search_and_command_line() {
+    let app = run(ScriptedEvents::new([Event::Paste(String::from("pika\n"))]));
+    assert!(matches!(app.tui.input_mode, InputMode::Editing));
+    assert_eq!(app.key_handle.input.value(), "pika ");
+    assert_eq!(profile_no(&app), Some(25));
+
+    let app = run(ScriptedEvents::keys(":go ")
+        .then(Event::Paste(String::from("charizard")))
+        .then_key(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!(profile_no(&app), Some(6));
+}
+
+#[test]
+fn resize_clamps_paragraph_scroll() {
+    let mut app = common::app();
+    let mut terminal = Terminal::new(TestBackend::new(SIZE.0, SIZE.1)).unwrap();
+
+    // scroll the abilities of Growlithe to the end
+    let ability = mouse_areas(ScriptedEvents::keys("58G")).ability;
+    let mut events = ScriptedEvents::keys("58G").then_mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        ability.right() - 1,
+        ability.bottom() - 1,
+    );
+    run_app(&mut terminal, &mut app, &mut events).unwrap();
+    assert!(app.pokemon_list.desc_scrollbar_state.position > 0);
+
+    // the abilities fit in a larger terminal
+    terminal.backend_mut().resize(200, 80);
+    let mut events = ScriptedEvents::new([Event::Resize(200, 80)]);
+    run_app(&mut terminal, &mut app, &mut events).unwrap();
+    let desc = &app.pokemon_list.desc_scrollbar_state;
+    assert_eq!((desc.height, desc.position), (0, 0));
+}
+
+fn tab(times: usize) -> ScriptedEvents {
+    (0..times).fold(ScriptedEvents::default(), |events, _| {
+        events.then_key(KeyCode::Tab, KeyModifiers::NONE)
+    })
+}
+
+#[test]
+fn focus_ring_skips_hidden_panes() {
+    let app = run(tab(1));
+    assert_eq!(app.tui.focused(), Pane::Art);
+
+    let app = run(ScriptedEvents::keys("V").chain(tab(2)));
+    assert_eq!(app.tui.focused(), Pane::Info);
+
+    let app = run(ScriptedEvents::default().then_key(KeyCode::BackTab, KeyModifiers::SHIFT));
+    assert_eq!(app.tui.focused(), Pane::Abilities);
+
+    // hiding the focused pane gives the focus back to the list
+    let app = run(tab(5).then_keys("A"));
+    assert_eq!(app.tui.focused(), Pane::List);
+
+    // Ivysaur has no dex entries, so its dex pane is not drawn
+    let app = run(tab(4));
+    assert_eq!(app.tui.focused(), Pane::Dex);
+    let app = run(ScriptedEvents::keys("2G").chain(tab(4)));
+    assert_eq!(app.tui.focused(), Pane::Abilities);
+    assert!(app.pokemon_list.mouse.dex.is_empty());
+}
+
+#[test]
+fn j_k_scroll_focused_pane() {
+    // Growlithe has enough ability text to scroll and no dex pane before it
+    let app = run(ScriptedEvents::keys("58G").chain(tab(4)).then_keys("jjjk"));
+    assert_eq!(app.pokemon_list.desc_scrollbar_state.position, 2);
+    assert_eq!(selected(&app), Some(57));
+
+    // alt+j is no longer special
+    let app = run(ScriptedEvents::default().then_key(KeyCode::Char('j'), KeyModifiers::ALT));
+    assert_eq!(selected(&app), Some(1));
+
+    // stats have nothing to scroll and move the list
+    let app = run(tab(2).then_keys("j"));
+    assert_eq!(selected(&app), Some(1));
+
+    // the art switches forms both ways
+    let app = run(ScriptedEvents::keys("6G").chain(tab(1)).then_keys("j"));
+    assert_eq!(app.pokemon_list.ascii_form_index, 1);
+    let app = run(ScriptedEvents::keys("6G").chain(tab(1)).then_keys("k"));
+    let forms = app.pokemon_list.ascii_forms().unwrap().len();
+    assert_eq!(app.pokemon_list.ascii_form_index, forms - 1);
+}
+
+#[test]
+fn mouse_click_focuses_pane() {
+    let ability = mouse_areas(ScriptedEvents::keys("58G")).ability;
+    let app = run(ScriptedEvents::keys("58G").then_mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        ability.x + 1,
+        ability.y + 1,
+    ));
+    assert_eq!(app.tui.focused(), Pane::Abilities);
+}
+
+fn enter() -> ScriptedEvents {
+    ScriptedEvents::default().then_key(KeyCode::Enter, KeyModifiers::NONE)
+}
+
+/// app in quiz mode asking pokemon `no`
+fn quiz(no: u16) -> AppState {
+    let mut app = common::app();
+    app.start_quiz(vec![]).unwrap();
+    let pm = app.pokemon_list.bundle.get_by_no(no).unwrap();
+    app.quiz.ask(pm);
+    app
+}
+
+#[test]
+fn quiz_takes_no_mouse_input() {
+    let list = mouse_areas(ScriptedEvents::default()).list;
+    let mut app = quiz(1);
+    run_on(
+        &mut app,
+        ScriptedEvents::default()
+            .then_mouse(MouseEventKind::ScrollDown, list.x, list.y)
+            .then_mouse(
+                MouseEventKind::Down(MouseButton::Left),
+                list.x + 1,
+                list.y + 3,
+            ),
+    );
+    assert_eq!(selected(&app), Some(0));
+    assert_eq!(app.tui.focused(), Pane::List);
+}
+
+#[test]
+fn quiz_command_filters_generations() {
+    let app = run(command("quiz 1 2"));
+    assert!(matches!(app.tui.input_mode, InputMode::Quiz));
+    assert!([1, 2].contains(&app.quiz.current.as_ref().unwrap().generation()));
+
+    let app = run(command("quiz 42"));
+    assert!(matches!(app.tui.input_mode, InputMode::Normal));
+    assert!(matches!(
+        app.command_line.message,
+        Some(CommandMessage::Error(_))
+    ));
+
+    let app = run(ScriptedEvents::keys("Q").then_key(KeyCode::Esc, KeyModifiers::NONE));
+    assert!(matches!(app.tui.input_mode, InputMode::Normal));
+    assert!(app.quiz.current.is_some());
+}
+
+#[test]
+fn quiz_accepts_any_language_and_typos() {
+    let mut app = quiz(1);
+    run_on(&mut app, ScriptedEvents::keys("ふしぎだね").chain(enter()));
+    assert_eq!(app.quiz.outcome, Some(QuizOutcome::Correct(10)));
+
+    // the second answer of a streak scores more
+    app.quiz.ask(app.pokemon_list.bundle.get_by_no(4).unwrap());
+    run_on(&mut app, ScriptedEvents::keys("charmandr").chain(enter()));
+    assert_eq!(app.quiz.outcome, Some(QuizOutcome::Correct(12)));
+    assert_eq!((app.quiz.score, app.quiz.streak), (22, 2));
+
+    app.quiz.ask(app.pokemon_list.bundle.get_by_no(25).unwrap());
+    run_on(&mut app, ScriptedEvents::keys("pikachuu").chain(enter()));
+    assert_eq!(app.quiz.streak, 3);
+
+    // enter goes on to the next question
+    run_on(&mut app, enter());
+    assert_eq!(app.quiz.outcome, None);
+    assert!(app.quiz.input.value().is_empty());
+}
+
+#[test]
+fn quiz_reveals_after_wrong_tries() {
+    let mut app = quiz(25);
+    app.quiz.streak = 4;
+
+    run_on(&mut app, ScriptedEvents::keys("raichu").chain(enter()));
+    assert_eq!(app.quiz.outcome, Some(QuizOutcome::Wrong(2)));
+    assert!(app.quiz.input.value().is_empty());
+
+    run_on(
+        &mut app,
+        ScriptedEvents::keys("pichu")
+            .chain(enter())
+            .then_keys("mew")
+            .chain(enter()),
+    );
+    assert_eq!(app.quiz.outcome, Some(QuizOutcome::Revealed));
+    assert_eq!(app.quiz.streak, 0);
+
+    // giving up ends the streak too
+    let mut app = quiz(25);
+    app.quiz.streak = 4;
+    run_on(
+        &mut app,
+        ScriptedEvents::default().then_key(KeyCode::Tab, KeyModifiers::NONE),
+    );
+    assert!(app.quiz.is_answered());
+    assert_eq!(app.quiz.streak, 0);
+}
+
+#[test]
+fn quiz_record_persists() {
+    let path = std::env::temp_dir()
+        .join(format!("poketex-quiz-{}", std::process::id()))
+        .join("quiz.json");
+
+    let mut app = quiz(1);
+    app.quiz = QuizState::default().path(path.clone());
+    app.quiz.ask(app.pokemon_list.bundle.get_by_no(1).unwrap());
+    run_on(&mut app, ScriptedEvents::keys("bulbasaur").chain(enter()));
+
+    let quiz = QuizState::default().path(path.clone());
+    assert_eq!(
+        quiz.record,
+        QuizRecord {
+            high_score: 10,
+            best_streak: 1
+        }
+    );
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+/// ask `attack` against `defense` in the trainer
+fn matchup(app: &mut AppState, attack: PokemonTypeKind, defense: PokemonType) {
+    app.trainer.ask(TypeQuestion {
+        matchup: Matchup { attack, defense },
+        species: None,
+    });
+}
+
+#[test]
+fn trainer_checks_answers_against_type_chart() {
+    use PokemonTypeKind::*;
+
+    let mut app = run(command("matchup"));
+    assert!(matches!(app.tui.input_mode, InputMode::Trainer));
+    assert!(app.trainer.question.is_some());
+
+    // 6 is 4x
+    matchup(&mut app, Ice, PokemonType(Dragon, Some(Flying)));
+    run_on(&mut app, ScriptedEvents::keys("6"));
+    assert!(app.trainer.is_correct());
+
+    // answered questions take no more answers
+    run_on(&mut app, ScriptedEvents::keys("1"));
+    assert_eq!(app.trainer.answer, Some(Effectiveness::Quadruple));
+
+    matchup(&mut app, Ground, PokemonType(Flying, None));
+    run_on(&mut app, ScriptedEvents::keys("5"));
+    assert!(!app.trainer.is_correct());
+
+    let record = &app.trainer.record;
+    assert_eq!(record.answered, 2);
+    assert_eq!((record.types[&Ice].right, record.types[&Ice].total), (1, 1));
+    assert_eq!(
+        (record.types[&Flying].right, record.types[&Flying].total),
+        (1, 2)
+    );
+    assert_eq!(record.weakest(1)[0].0, Ground);
+
+    run_on(&mut app, enter().then_key(KeyCode::Esc, KeyModifiers::NONE));
+    assert!(!app.trainer.is_answered());
+    assert!(matches!(app.tui.input_mode, InputMode::Normal));
+}
+
+#[test]
+fn trainer_takes_no_mouse_input() {
+    let list = mouse_areas(ScriptedEvents::default()).list;
+    let app = run(command("matchup")
+        .then_mouse(MouseEventKind::ScrollDown, list.x, list.y)
+        .then_mouse(
+            MouseEventKind::Down(MouseButton::Left),
+            list.x + 1,
+            list.y + 3,
+        ));
+    assert!(matches!(app.tui.input_mode, InputMode::Trainer));
+    assert_eq!(selected(&app), Some(0));
+    assert_eq!(app.tui.focused(), Pane::List);
+}
+
+#[test]
+fn trainer_repeats_missed_matchups() {
+    use PokemonTypeKind::*;
+
+    let mut app = common::app();
+    app.trainer = TrainerState::default().seed(7);
+    let missed = Matchup {
+        attack: Electric,
+        defense: PokemonType(Ground, None),
+    };
+
+    matchup(&mut app, missed.attack, missed.defense);
+    app.trainer.answer(Effectiveness::Normal);
+    assert_eq!(app.trainer.record.reviews.len(), 1);
+
+    // asked again after 2 other questions
+    let mut asked = vec![];
+    for _ in 0..3 {
+        app.next_trainer();
+        let question = app.trainer.question.as_ref().unwrap().matchup;
+        asked.push(question == missed);
+        app.trainer.answer(question.effectiveness());
+    }
+    assert_eq!(asked, [false, false, true]);
+
+    // then less often until it is learned
+    let review = app.trainer.record.reviews[0];
+    assert_eq!((review.level, review.due), (1, 4 + 4));
+
+    app.trainer.record.reviews[0].level = 3;
+    matchup(&mut app, missed.attack, missed.defense);
+    app.trainer.answer(Effectiveness::NoEffect);
+    assert!(app.trainer.record.reviews.is_empty());
+}
+
+#[test]
+fn trainer_record_persists() {
+    let path = std::env::temp_dir()
+        .join(format!("poketex-trainer-{}", std::process::id()))
+        .join("trainer.json");
+
+    let mut app = common::app();
+    app.trainer = TrainerState::default().path(path.clone());
+    matchup(
+        &mut app,
+        PokemonTypeKind::Fire,
+        PokemonType(PokemonTypeKind::Grass, Some(PokemonTypeKind::Steel)),
+    );
+    app.trainer.answer(Effectiveness::Double);
+
+    let trainer = TrainerState::default().path(path.clone());
+    assert_eq!(trainer.record, app.trainer.record);
+    assert_eq!(trainer.record.reviews.len(), 1);
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn art_cache_reads_each_art_once() {
+    let app = run(ScriptedEvents::keys("jjkk"));
+    let stats = app.pokemon_list.art_cache.stats;
+    assert_eq!(stats.loads, 3);
+    assert!(stats.hits >= 2);
+    assert!(stats.slowest >= stats.average());
+
+    let app = run(ScriptedEvents::keys("jk").chain(command("cache")));
+    let Some(CommandMessage::Info(message)) = &app.command_line.message else {
+        panic!("no cache stats");
+    };
+    assert!(message.starts_with("art cache: "), "{}", message);
+}
+
+#[test]
+fn art_cache_prefetches_neighbors_while_idle() {
+    let key = |app: &AppState, no| {
+        let list = &app.pokemon_list;
+        list.art_key(&list.bundle.get_by_no(no).unwrap(), "regular", false)
+    };
+
+    let app = run(ScriptedEvents::keys("5G"));
+    assert!(!app.pokemon_list.art_cache.contains(&key(&app, 6)));
+
+    let mut app = run(ScriptedEvents::keys("5G").then_wait(Duration::from_millis(300)));
+    for no in [3, 4, 6, 7] {
+        assert!(
+            app.pokemon_list.art_cache.contains(&key(&app, no)),
+            "#{}",
+            no
+        );
+    }
+    assert_eq!(app.pokemon_list.art_cache.stats.prefetched, 4);
+
+    // moving on is a hit
+    let loads = app.pokemon_list.art_cache.stats.loads;
+    run_on(&mut app, ScriptedEvents::keys("j"));
+    assert_eq!(app.pokemon_list.art_cache.stats.loads, loads);
+}
+
+#[test]
+fn art_cache_evicts_least_recently_used() {
+    let list = common::list_state();
+    let key = |no| list.art_key(&list.bundle.get_by_no(no).unwrap(), "regular", false);
+
+    let mut cache = ArtCache::new(2);
+    assert!(cache.get(&key(1)).is_some());
+    cache.get(&key(2));
+    cache.get(&key(1));
+    cache.get(&key(3));
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains(&key(1)));
+    assert!(!cache.contains(&key(2)));
+
+    // missing art is cached too
+    let missing = ArtKey {
+        name: String::from("missingno"),
+        ..key(1)
+    };
+    assert!(cache.get(&missing).is_none());
+    assert!(cache.contains(&missing));
+}
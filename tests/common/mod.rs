@@ -0,0 +1,71 @@
+#![allow(dead_code)]
+
+use std::{fs, path::Path, rc::Rc};
+
+use poketex::{
+    data::PokemonData,
+    pokemon::ascii_form::AsciiForms,
+    state::{tui::TuiState, AppState, PokemonListState},
+};
+use ratatui::buffer::Buffer;
+
+pub fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+pub fn list_state() -> PokemonListState {
+    let (bundle, ascii) = PokemonData::embedded()
+        .expect("bundled data should load")
+        .bundle();
+
+    PokemonListState::new(Rc::new(bundle), AsciiForms::from(ascii))
+        .path(root().join("colorscripts/small"))
+}
+
+pub fn app() -> AppState {
+    AppState {
+        pokemon_list: list_state(),
+        tui: TuiState::new(),
+        ..Default::default()
+    }
+}
+
+pub fn buffer_to_string(buf: &Buffer) -> String {
+    let area = buf.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            (area.left()..area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+/// compare the buffer with `tests/snapshots/<name>.txt`,
+/// run with `UPDATE_SNAPSHOTS=1` to write new snapshots
+pub fn assert_snapshot(name: &str, buf: &Buffer) {
+    let path = root().join("tests/snapshots").join(format!("{}.txt", name));
+    let actual = buffer_to_string(buf);
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, &actual).expect("failed to write snapshot");
+        return;
+    }
+
+    let Ok(expected) = fs::read_to_string(&path) else {
+        panic!(
+            "snapshot {} is missing, run with UPDATE_SNAPSHOTS=1 to create it\n{}",
+            path.display(),
+            actual
+        );
+    };
+
+    assert!(
+        expected == actual,
+        "snapshot {} does not match\n--- expected\n{}\n--- actual\n{}",
+        name,
+        expected,
+        actual
+    );
+}
@@ -0,0 +1,253 @@
+mod common;
+
+use std::path::PathBuf;
+
+use common::{list_state, root};
+use poketex::{
+    data::{validate, PokemonData},
+    i18n::catalog,
+    pokemon::{
+        AltName, BreedingIssue, BreedingResult, EggGroup, GrowthRate, Locale, TranslateText,
+    },
+};
+
+fn data() -> PokemonData {
+    PokemonData::embedded().expect("bundled data should load")
+}
+
+fn art_name(name: &str, form: &str) -> String {
+    if form == "regular" {
+        name.to_string()
+    } else {
+        format!("{}-{}", name, form)
+    }
+}
+
+#[test]
+fn ability_ids_exist() {
+    let data = data();
+
+    for pm in data.pokemon.iter() {
+        for id in pm.ability.iter() {
+            assert!(
+                data.ability.contains_key(id),
+                "#{} refers to unknown ability {}",
+                pm.no,
+                id
+            );
+        }
+
+        for form in pm.form.iter().flatten() {
+            for id in form.ability.iter() {
+                assert!(
+                    data.ability.contains_key(id),
+                    "#{} {:?} refers to unknown ability {}",
+                    pm.no,
+                    form.form,
+                    id
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn ascii_forms_have_art() {
+    let data = data();
+    let colorscripts = root().join("colorscripts");
+    let mut missing: Vec<PathBuf> = vec![];
+
+    for item in data.ascii.iter() {
+        for form in item.forms.iter() {
+            let name = art_name(&item.name, form);
+            for dir in [
+                "small/regular",
+                "small/shiny",
+                "large/regular",
+                "large/shiny",
+            ] {
+                let path = colorscripts.join(dir).join(&name);
+                if !path.is_file() {
+                    missing.push(path);
+                }
+            }
+        }
+    }
+
+    assert!(missing.is_empty(), "missing art files: {:#?}", missing);
+}
+
+#[test]
+fn dex_numbers_are_contiguous() {
+    let data = data();
+
+    for (i, pm) in data.pokemon.iter().enumerate() {
+        assert_eq!(
+            pm.no as usize,
+            i + 1,
+            "dex number gap or duplicate at #{}",
+            pm.no
+        );
+    }
+}
+
+#[test]
+fn region_forms_are_translated() {
+    let data = data();
+
+    for pm in data.pokemon.iter() {
+        let Some(forms) = pm.region_form() else {
+            continue;
+        };
+
+        for form in forms {
+            for name in form.name.values() {
+                assert!(
+                    name.split(" - ").all(|part| !part.is_empty()),
+                    "#{} has an untranslated form: {:?}",
+                    pm.no,
+                    name
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn species_metadata_is_complete() {
+    let data = data();
+
+    // records with metadata have all of it, and these ones have it
+    let described = [1, 2, 3, 4, 5, 6, 7, 8, 9, 25, 26, 132, 172];
+    for pm in data.pokemon.iter() {
+        let s = &pm.species;
+        let fields = [
+            ("height", s.height.is_some()),
+            ("weight", s.weight.is_some()),
+            ("category", s.category.is_some()),
+            ("egg_group", !s.egg_group.is_empty()),
+            ("catch_rate", s.catch_rate.is_some()),
+            ("base_exp", s.base_exp.is_some()),
+            ("growth_rate", s.growth_rate.is_some()),
+            ("friendship", s.friendship.is_some()),
+        ];
+        if !described.contains(&pm.no) && fields.iter().all(|(_, present)| !present) {
+            continue;
+        }
+
+        for (field, present) in fields {
+            assert!(present, "#{} has no {}", pm.no, field);
+        }
+    }
+
+    let bulbasaur = &data.pokemon[0].species;
+    assert_eq!(bulbasaur.height, Some(0.7));
+    assert_eq!(bulbasaur.weight, Some(6.9));
+    assert_eq!(bulbasaur.egg_group, [EggGroup::Monster, EggGroup::Grass]);
+    assert_eq!(bulbasaur.growth_rate, Some(GrowthRate::MediumSlow));
+    assert_eq!(
+        bulbasaur.category.as_ref().map(|c| c.get(Locale::Ja)),
+        Some(String::from("たねポケモン"))
+    );
+}
+
+#[test]
+fn ditto_breeds_with_bundled_species() {
+    let (bundle, _) = data().bundle();
+    let ditto = bundle.get_by_no(132).unwrap();
+    let ivysaur = bundle.get_by_no(2).unwrap();
+
+    // the offspring is the base form of the partner, whichever parent Ditto is
+    for (mother, father) in [(&ditto, &ivysaur), (&ivysaur, &ditto)] {
+        let BreedingResult::Compatible { offspring, .. } = bundle.check_breeding(mother, father)
+        else {
+            panic!("Ditto and Ivysaur should be compatible");
+        };
+        assert_eq!(offspring.no, 1);
+    }
+
+    let pikachu = bundle.get_by_no(25).unwrap();
+    let BreedingResult::Compatible { offspring, .. } = bundle.check_breeding(&pikachu, &ditto)
+    else {
+        panic!("Pikachu and Ditto should be compatible");
+    };
+    assert_eq!(offspring.no, 172);
+
+    assert!(matches!(
+        bundle.check_breeding(&ditto, &ditto),
+        BreedingResult::Incompatible(BreedingIssue::BothDitto)
+    ));
+
+    let monsters = bundle
+        .egg_group_members(EggGroup::Monster)
+        .iter()
+        .map(|pm| pm.no)
+        .collect::<Vec<_>>();
+    assert!([1, 4, 7].iter().all(|no| monsters.contains(no)));
+}
+
+#[test]
+fn bundled_data_dir_is_valid() {
+    let problems = validate(
+        &root().join("data"),
+        &root().join("colorscripts/small"),
+        None,
+    );
+    let problems = problems.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+
+    assert!(problems.is_empty(), "{:#?}", problems);
+}
+
+#[test]
+fn translations_fall_back() {
+    let text: TranslateText = serde_json::from_str(
+        r#"{"en": "Bulbasaur", "jp": "フシギダネ", "zh": "妙蛙種子", "ko": ""}"#,
+    )
+    .unwrap();
+
+    assert_eq!(text.get(Locale::Ja), "フシギダネ");
+    assert_eq!(text.get(Locale::ZhHans), "妙蛙種子");
+    assert_eq!(text.get(Locale::Ko), "Bulbasaur");
+    assert_eq!(Locale::parse("zh_CN"), Some(Locale::ZhHans));
+    assert_eq!(Locale::parse("fr-FR"), Some(Locale::Fr));
+}
+
+#[test]
+fn catalog_keys_exist_in_english() {
+    let en = catalog(Locale::En);
+
+    for locale in Locale::ALL {
+        for key in catalog(locale).keys() {
+            assert!(en.contains_key(key), "{} has unknown key {}", locale, key);
+        }
+    }
+}
+
+#[test]
+fn romaji_names_have_no_kana_left() {
+    for pm in data().pokemon.iter() {
+        let name = AltName::Romaji.text(&pm.name).unwrap_or_default();
+        assert!(
+            name.chars().all(|c| c.is_ascii() || matches!(c, '♂' | '♀')),
+            "#{} has kana left in {}",
+            pm.no,
+            name
+        );
+    }
+}
+
+#[test]
+fn missing_art_lists_every_form() {
+    let dir = std::env::temp_dir().join(format!("poketex-missing-{}", std::process::id()));
+
+    // every form without art is listed with its file
+    let missing = list_state().path(dir.clone()).missing_art();
+    assert!(missing
+        .iter()
+        .any(|key| key.path() == dir.join("regular/pikachu")));
+    assert!(missing.iter().any(|key| key.file() == "charizard-mega-x"));
+    assert!(list_state()
+        .missing_art()
+        .iter()
+        .all(|key| !key.path().exists()));
+}
@@ -0,0 +1,415 @@
+mod common;
+
+use std::path::PathBuf;
+
+use common::{app, assert_snapshot, list_state};
+use poketex::{
+    pokemon::{AltName, Effectiveness, Locale, PokemonTypeKind},
+    sprite::{iterm2, kitty, sixel, ImageProtocol, SpriteRenderer, KITTY_CLEAR},
+    state::{
+        trainer::{Matchup, TypeQuestion},
+        tui::{Pane, TuiState},
+        InputMode,
+    },
+    ui::ui,
+    widget::{
+        filter::Filter, help::Help, pokemon_list::PokemonList, profile::PokemonProfileWidget,
+        quiz::Quiz, trainer::Trainer,
+    },
+};
+use ratatui::{
+    backend::TestBackend, buffer::Buffer, layout::Rect, style::Color, widgets::StatefulWidget,
+    Terminal,
+};
+
+fn render<F: FnOnce(&mut ratatui::Frame)>(width: u16, height: u16, f: F) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(f).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn render_profile(name: &str, width: u16, height: u16, tui: TuiState, select: usize) {
+    let mut state = list_state();
+    state.select(select);
+
+    let buf = render(width, height, |f| {
+        f.render_stateful_widget(PokemonProfileWidget(tui), f.area(), &mut state)
+    });
+
+    assert_snapshot(name, &buf);
+}
+
+#[test]
+fn profile_only_ansi() {
+    render_profile("profile_only_ansi", 18, 10, TuiState::new(), 0);
+}
+
+#[test]
+fn profile_horizontal() {
+    render_profile("profile_horizontal", 120, 16, TuiState::new(), 0);
+}
+
+#[test]
+fn profile_rect_ja() {
+    let mut state = list_state().locale(Locale::Ja);
+    state.select(24);
+
+    let buf = render(100, 45, |f| {
+        f.render_stateful_widget(PokemonProfileWidget(TuiState::new()), f.area(), &mut state)
+    });
+
+    assert_snapshot("profile_rect_ja", &buf);
+}
+
+#[test]
+fn profile_alt_names() {
+    let mut state = list_state().alt_names(vec![AltName::Locale(Locale::Ja), AltName::Romaji]);
+    state.select(24);
+
+    let buf = render(100, 45, |f| {
+        f.render_stateful_widget(PokemonProfileWidget(TuiState::new()), f.area(), &mut state)
+    });
+
+    assert_snapshot("profile_alt_names", &buf);
+}
+
+#[test]
+fn focused_pane_border_is_highlighted() {
+    let mut state = list_state();
+    let tui = TuiState {
+        focus: Pane::Abilities,
+        ..TuiState::new()
+    };
+
+    let buf = render(100, 45, |f| {
+        f.render_stateful_widget(PokemonProfileWidget(tui), f.area(), &mut state)
+    });
+
+    let ability = state.mouse.ability;
+    let dex = state.mouse.dex;
+    assert_eq!(buf[(ability.x, ability.y)].fg, Color::LightGreen);
+    assert_eq!(buf[(dex.x, dex.y)].fg, Color::Reset);
+}
+
+#[test]
+fn profile_vertical() {
+    render_profile("profile_vertical", 40, 60, TuiState::new(), 5);
+}
+
+#[test]
+fn profile_rect() {
+    render_profile("profile_rect", 100, 45, TuiState::new(), 24);
+}
+
+#[test]
+fn profile_rect_with_region_form() {
+    let mut state = list_state();
+    // Growlithe has a Hisuian form
+    state.select(57);
+    state.next_profile_page();
+
+    let buf = render(100, 45, |f| {
+        f.render_stateful_widget(PokemonProfileWidget(TuiState::new()), f.area(), &mut state)
+    });
+
+    assert_snapshot("profile_rect_with_region_form", &buf);
+}
+
+#[test]
+fn profile_without_details() {
+    let tui = TuiState {
+        show_abilities: false,
+        show_info: false,
+        show_dex: false,
+        ..TuiState::new()
+    };
+
+    render_profile("profile_without_details", 100, 30, tui, 0);
+}
+
+#[test]
+fn profile_without_iv_and_details() {
+    let tui = TuiState {
+        show_iv: false,
+        show_abilities: false,
+        show_info: false,
+        show_dex: false,
+        ..TuiState::new()
+    };
+
+    render_profile("profile_without_iv_and_details", 60, 30, tui, 0);
+}
+
+#[test]
+fn pokemon_list() {
+    let mut state = list_state();
+    state.select(30);
+
+    let buf = render(30, 20, |f| {
+        f.render_stateful_widget(PokemonList(TuiState::new()), f.area(), &mut state)
+    });
+
+    assert_snapshot("pokemon_list", &buf);
+}
+
+#[test]
+fn pokemon_list_filtered() {
+    let mut state = list_state();
+    state.set_list_filter(String::from("saur"));
+
+    let buf = render(30, 10, |f| {
+        f.render_stateful_widget(PokemonList(TuiState::new()), f.area(), &mut state)
+    });
+
+    assert_snapshot("pokemon_list_filtered", &buf);
+}
+
+#[test]
+fn pokemon_list_column() {
+    let mut state = list_state().alt_names(vec![AltName::Romaji]);
+    let tui = TuiState {
+        show_list_column: true,
+        ..TuiState::new()
+    };
+
+    let buf = render(45, 10, |f| {
+        f.render_stateful_widget(PokemonList(tui), f.area(), &mut state)
+    });
+
+    assert_snapshot("pokemon_list_column", &buf);
+}
+
+#[test]
+fn filter_normal() {
+    let mut app = app();
+
+    let buf = render(30, 1, |f| {
+        f.render_stateful_widget(Filter, f.area(), &mut app)
+    });
+
+    assert_snapshot("filter_normal", &buf);
+    assert_eq!(app.tui.cursor, None);
+}
+
+#[test]
+fn filter_editing() {
+    let mut app = app();
+    app.tui.input_mode = InputMode::Editing;
+    app.key_handle.input = "pika".into();
+
+    let buf = render(30, 3, |f| {
+        f.render_stateful_widget(Filter, f.area(), &mut app)
+    });
+
+    assert_snapshot("filter_editing", &buf);
+    assert_eq!(app.tui.cursor, Some((8, 1)));
+}
+
+#[test]
+fn help() {
+    let buf = render(60, 30, |f| f.render_widget(Help(Locale::En), f.area()));
+
+    assert_snapshot("help", &buf);
+}
+
+#[test]
+fn help_ja() {
+    let buf = render(60, 30, |f| f.render_widget(Help(Locale::Ja), f.area()));
+
+    assert_snapshot("help_ja", &buf);
+}
+
+#[test]
+fn profile_missing_art() {
+    let mut state = list_state().path("missing/colorscripts/small".into());
+    state.select(24);
+
+    let buf = render(80, 30, |f| {
+        f.render_stateful_widget(PokemonProfileWidget(TuiState::new()), f.area(), &mut state)
+    });
+
+    // the file looked for is named in place of the art
+    let screen = common::buffer_to_string(&buf);
+    assert!(screen.contains("small/regular/pikachu"));
+    assert_snapshot("profile_missing_art", &buf);
+}
+
+#[test]
+fn sprite_encoders() {
+    assert_eq!(
+        kitty(b"png", 2, 1),
+        "\x1b_Ga=d,d=A,q=2\x1b\\\x1b_Ga=T,f=100,q=2,c=2,r=1,m=0;cG5n\x1b\\"
+    );
+    // payloads over 4096 bytes are sent in chunks
+    let chunked = kitty(&[0; 4000], 10, 5);
+    assert_eq!(chunked.matches("\x1b_G").count(), 3);
+    assert!(chunked.contains(",m=1;") && chunked.contains("\x1b_Gm=0;"));
+
+    assert_eq!(
+        iterm2(b"png", 2, 1),
+        "\x1b]1337;File=inline=1;size=3;width=2;height=1;preserveAspectRatio=1:cG5n\x07"
+    );
+
+    // red and nothing over nothing and blue, one band of two rows
+    let rgba = [[255, 0, 0, 255], [0; 4], [0; 4], [0, 0, 255, 255]].concat();
+    assert_eq!(
+        sixel(2, 2, &rgba),
+        "\x1bP0;1;0q\"1;1;2;2#5;2;0;0;100#180;2;100;0;0#5?A$#180@?$\x1b\\"
+    );
+    // runs are compressed and bands are split by `-`
+    let rgba = [255, 0, 0, 255].repeat(5 * 7);
+    assert_eq!(
+        sixel(5, 7, &rgba),
+        "\x1bP0;1;0q\"1;1;5;7#180;2;100;0;0#180!5~$-#180!5@$\x1b\\"
+    );
+}
+
+#[test]
+fn image_protocol_detection() {
+    let detect = |vars: &[(&str, &str)]| {
+        ImageProtocol::detect(|name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        })
+    };
+
+    assert_eq!(
+        detect(&[("TERM", "xterm-kitty")]),
+        Some(ImageProtocol::Kitty)
+    );
+    assert_eq!(
+        detect(&[("TERM_PROGRAM", "iTerm.app")]),
+        Some(ImageProtocol::Iterm2)
+    );
+    assert_eq!(detect(&[("TERM", "foot")]), Some(ImageProtocol::Sixel));
+    assert_eq!(detect(&[("TERM", "xterm-256color")]), None);
+    // tmux needs passthrough set up, so it is left to --image-protocol
+    assert_eq!(
+        detect(&[("TERM", "xterm-kitty"), ("TMUX", "/tmp/tmux")]),
+        None
+    );
+    assert_eq!(ImageProtocol::parse("Sixel"), Some(ImageProtocol::Sixel));
+}
+
+/// sprite directory with a 4x6 pikachu
+fn sprite_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("poketex-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(dir.join("regular")).unwrap();
+    let mut png = vec![];
+    let mut encoder = png::Encoder::new(&mut png, 4, 6);
+    encoder.set_color(png::ColorType::Rgba);
+    let mut writer = encoder.write_header().unwrap();
+    writer.write_image_data(&[200; 4 * 6 * 4]).unwrap();
+    writer.finish().unwrap();
+    std::fs::write(dir.join("regular/pikachu.png"), png).unwrap();
+    dir
+}
+
+#[test]
+fn profile_sprite() {
+    let dir = sprite_dir("sprites");
+
+    // skipped cells only show in the buffer, the test backend leaves them out
+    let profile_buffer = |state: &mut _| {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 30));
+        PokemonProfileWidget(TuiState::new()).render(buf.area, &mut buf, state);
+        buf
+    };
+
+    // scaled 10 times to 40x60 pixels, over 20x15 cells of 2x4
+    let renderer = SpriteRenderer::new(ImageProtocol::Sixel, dir.clone()).cell_size(2, 4);
+    let mut state = list_state().sprites(Some(renderer));
+    state.select(24);
+
+    let buf = profile_buffer(&mut state);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let sprite = state.sprite().unwrap();
+    assert_eq!((sprite.cols, sprite.rows), (20, 15));
+    assert_eq!(buf[(0, 2)].symbol(), format!("\x1b7{}\x1b8", sprite.escape));
+    let skipped = buf.content().iter().filter(|cell| cell.skip).count();
+    assert_eq!(skipped, 20 * 15 - 1);
+
+    // the ansi art is drawn when sprites are off
+    state.sprites.as_mut().unwrap().enabled = false;
+    let buf = profile_buffer(&mut state);
+    assert!(buf.content().iter().all(|cell| !cell.skip));
+}
+
+#[test]
+fn popup_hides_kitty_sprite() {
+    let dir = sprite_dir("kitty");
+    let mut app = app();
+    app.pokemon_list = list_state().sprites(Some(
+        SpriteRenderer::new(ImageProtocol::Kitty, dir.clone()).cell_size(2, 4),
+    ));
+    app.pokemon_list.select(24);
+
+    let render_ui = |app: &mut _| render(120, 40, |f| ui(f, app));
+    let has_image = |buf: &Buffer| {
+        buf.content()
+            .iter()
+            .any(|cell| cell.symbol().contains("a=T"))
+    };
+
+    let buf = render_ui(&mut app);
+    assert!(has_image(&buf));
+
+    // the image is deleted while help is open, and comes back after
+    app.tui.show_help = true;
+    let buf = render_ui(&mut app);
+    assert!(!has_image(&buf));
+    assert!(buf
+        .content()
+        .last()
+        .unwrap()
+        .symbol()
+        .starts_with(KITTY_CLEAR));
+
+    app.tui.show_help = false;
+    let buf = render_ui(&mut app);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(has_image(&buf));
+}
+
+#[test]
+fn quiz_silhouette() {
+    let mut app = app();
+    app.start_quiz(vec![]).unwrap();
+    app.quiz.ask(app.pokemon_list.bundle.get_by_no(25).unwrap());
+
+    let buf = render(60, 30, |f| {
+        f.render_stateful_widget(Quiz, f.area(), &mut app)
+    });
+
+    // the art is drawn in a single color until it is answered
+    let colors = buf
+        .content()
+        .iter()
+        .flat_map(|cell| [cell.fg, cell.bg])
+        .filter(|c| !matches!(c, Color::Reset | Color::Yellow))
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(colors, [Color::DarkGray].into());
+
+    assert_snapshot("quiz_silhouette", &buf);
+}
+
+#[test]
+fn trainer_answer() {
+    let mut app = app();
+    let dragonite = app.pokemon_list.bundle.get_by_no(149).unwrap();
+    app.trainer.ask(TypeQuestion {
+        matchup: Matchup {
+            attack: PokemonTypeKind::Ice,
+            defense: dragonite.r#type,
+        },
+        species: Some(dragonite),
+    });
+    app.trainer.answer(Effectiveness::Double);
+
+    let buf = render(60, 14, |f| {
+        f.render_stateful_widget(Trainer, f.area(), &mut app)
+    });
+    assert_snapshot("trainer_answer", &buf);
+}